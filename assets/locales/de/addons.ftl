@@ -1,4 +1,5 @@
 game-addons = Spielerweiterungen
+game-settings = Spieleinstellungen
 
 addon-install = Installieren
 addon-uninstall = Deinstallieren 
@@ -1,9 +1,15 @@
 details-developer = Entwicker: {$developer}
 details-played = Spielzeit: {$played}
 details-last-played = Zuletzt gespielt: {$last-played}
+details-tags = Tags: {$tags}
+
+details-homepage = Webseite
+details-support = Support
 
 details-play = Spielen
 details-kill = Beenden
 details-verify = Verifizieren
 details-manage-addons = Erweiterungen verwalten
 details-download = Herunterladen
+
+details-news = Neuigkeiten
@@ -1,7 +1,10 @@
 dialog-toast-details = Details
+dialog-error-trace   = Fehlerverlauf
 
 dialog-stop     = Stop
 dialog-disable  = Deaktivieren
 dialog-continue = Fortsetzen
 dialog-close    = Schließen
 dialog-save     = Speichen
+dialog-yes      = Ja
+dialog-no       = Nein
@@ -20,10 +20,12 @@ game-get-paths-failed           = Pfade für {$game-title} sind nicht verfügbar
 game-get-integrity-failed       = Integritätsinformationen für {$game-title} sind nicht verfügbar
 game-get-status-failed          = Der Status von {$game-title} ist nicht verfügbar
 game-not-installed              = {$game-title} ist nicht installiert
+game-integration-not-loaded     = Die Integration von {$game-title} ist nicht geladen
 game-launch-failed              = {$game-title} konnte nicht gestartet werden
 game-kill-failed                = {$game-title} konnte nicht beendet werden
 
 launch-required-addon-unavailable = Die Erweiterung {$addon-title} der Gruppe {$group-title} ist nicht verfügbar oder veraltet. Es ist möglich das Spiel ohne dies zu starten oder mit der veralteten Version fortzufahren
+launch-incompatible-components    = Die ausgewählten Komponenten funktionieren möglicherweise nicht mit {$game-title}. Du kannst sie in den Einstellungen ändern oder das Spiel trotzdem starten
 
 loading-preparing-folders-failed     = Standartordner konnten nicht vorbereitet werden
 loading-initializing-debug-failed    = Die Initializierung der Debuginformationsausgabe ist fehlgeschlagen
@@ -38,6 +40,22 @@ loading-checking-dxvk-version-failed = Die Überprüfung der DXVK Version ist fe
 loading-checking-applied-dxvk-failed = Die Überprüfung der angewandten DXVK Version ist fehlgeschlafen
 loading-checking-games-addons-failed = Die Überprüfung der Spieladdons ist fehlgeschlagen
 
+loading-integrations-rejected = Einige Integrationen wurden abgelehnt, da die Signaturprüfung fehlgeschlagen ist
+loading-integrations-skipped  = Einige Integrationen sind nicht mit dieser Launcher-Version kompatibel
+
 main-open-launcher-folder-failed = Der Launcher Order konnte nicht geöffnet werden
 main-open-config-file-failed     = Der Konfigurationsordner konnte nicht geöffnet werden
 main-open-debug-file-failed      = Die Debugdatei konnte nicht geöffnet werden
+details-open-news-failed         = Der Link der Neuigkeit konnte nicht geöffnet werden
+details-open-link-failed         = Der Link konnte nicht geöffnet werden
+
+main-broken-integrations-retry-failed  = Die Integration konnte nicht geladen werden
+main-broken-integrations-remove-failed = Die Integration konnte nicht entfernt werden
+main-reload-integration-failed         = Die Integration {$integration} konnte nicht neu geladen werden
+
+script-error-api-unavailable     = Die Spiel-API ist nicht verfügbar. Versuche es später erneut
+script-error-network-unavailable = Das Netzwerk ist nicht verfügbar
+script-error-rate-limited        = Zu viele Anfragen an die Spiel-API. Versuche es später erneut
+script-error-invalid-response    = Die Spiel-API hat eine unerwartete Antwort zurückgegeben
+script-error-not-found           = Die angeforderten Spieldaten wurden nicht gefunden
+script-error-not-supported       = Diese Aktion wird von der Integration nicht unterstützt
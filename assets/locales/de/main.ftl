@@ -7,3 +7,11 @@ main-installed-games = Installierte Spiele
 main-queued-games    = Ausstehende Spiele
 main-outdated-games  = Veraltete Spiele
 main-available-games = Verfügbare Spiele
+
+main-broken-integrations        = Fehlerhafte Integrationen
+main-broken-integrations-retry  = Wiederholen
+main-broken-integrations-remove = Entfernen
+
+game-card-dev = dev
+
+main-offline-banner = Das Netzwerk ist nicht verfügbar. Die angezeigten Informationen können veraltet sein
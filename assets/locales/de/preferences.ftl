@@ -62,3 +62,9 @@ components-dxvk-latest = Neuste
 
 components-install-corefonts = Installiere corefonts
 components-install-corefonts-description = Installiere die standart Windows Schriftarten in den Wine Prefix
+
+components-incompatible = Nicht kompatibel mit einigen installierten Spielen
+
+incompatible-components   = Inkompatible Komponenten
+compatibility-unsupported = {$component} {$version} wird vom Spiel nicht unterstützt
+compatibility-blacklisted = {$component} {$version} ist dafür bekannt, das Spiel zu beschädigen
@@ -19,6 +19,7 @@ tasks-post-transition-code = Führe Nachübergangscodeaufgabe aus...
 tasks-creating-prefix      = Erstelle Prefix...
 tasks-installing-dxvk      = Installiere DXVK...
 tasks-installing-fonts     = Installiere Schriftarten...
+tasks-applying-requirements = Wende Spielanforderungen an...
 tasks-verifying-files      = Verifiziere Dateien...
 tasks-repairing-files      = Repariere Dateien...
 tasks-finished             = Fertig
@@ -1,4 +1,5 @@
 game-addons = Game addons
+game-settings = Game settings
 
 addon-install = Install
 addon-uninstall = Uninstall
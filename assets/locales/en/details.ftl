@@ -1,9 +1,15 @@
 details-developer = Developer: {$developer}
 details-played = Played: {$played}
 details-last-played = Last played: {$last-played}
+details-tags = Tags: {$tags}
+
+details-homepage = Homepage
+details-support = Support
 
 details-play = Play
 details-kill = Kill
 details-verify = Verify
 details-manage-addons = Manage addons
 details-download = Download
+
+details-news = News
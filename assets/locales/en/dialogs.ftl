@@ -1,7 +1,10 @@
 dialog-toast-details = Details
+dialog-error-trace   = Error trace
 
 dialog-stop     = Stop
 dialog-disable  = Disable
 dialog-continue = Continue
 dialog-close    = Close
 dialog-save     = Save
+dialog-yes      = Yes
+dialog-no       = No
@@ -20,10 +20,12 @@ game-get-paths-failed           = Unable to get {$game-title} paths
 game-get-integrity-failed       = Unable to get {$game-title} integrity info
 game-get-status-failed          = Unable to get {$game-title} status
 game-not-installed              = {$game-title} is not installed
+game-integration-not-loaded     = {$game-title} integration is not loaded
 game-launch-failed              = Failed to launch {$game-title}
 game-kill-failed                = Failed to kill {$game-title}
 
 launch-required-addon-unavailable = Addon {$addon-title} from group {$group-title} is unavailable or outdated. You can launch the game without it or continue to use old version
+launch-incompatible-components    = Selected components may not work with {$game-title}. You can change them in preferences or launch the game anyway
 
 loading-preparing-folders-failed     = Failed to prepare default folders
 loading-initializing-debug-failed    = Failed to initialize debug output
@@ -38,6 +40,22 @@ loading-checking-dxvk-version-failed = Failed to check dxvk version
 loading-checking-applied-dxvk-failed = Failed to check applied dxvk version
 loading-checking-games-addons-failed = Failed to check games addons
 
+loading-integrations-rejected = Some integrations were rejected because of failed signature verification
+loading-integrations-skipped  = Some integrations are not compatible with this launcher version
+
 main-open-launcher-folder-failed = Failed to open launcher folder
 main-open-config-file-failed     = Failed to open config file
 main-open-debug-file-failed      = Failed to open debug file
+details-open-news-failed         = Failed to open news link
+details-open-link-failed         = Failed to open link
+
+main-broken-integrations-retry-failed  = Failed to load integration
+main-broken-integrations-remove-failed = Failed to remove integration
+main-reload-integration-failed         = Failed to reload {$integration} integration
+
+script-error-api-unavailable     = Game API is not available. Try again later
+script-error-network-unavailable = Network is not available
+script-error-rate-limited        = Too many requests to the game API. Try again later
+script-error-invalid-response    = Game API returned unexpected response
+script-error-not-found           = Requested game data was not found
+script-error-not-supported       = This action is not supported by the integration
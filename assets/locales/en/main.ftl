@@ -7,3 +7,11 @@ main-installed-games = Installed games
 main-queued-games    = Queued games
 main-outdated-games  = Outdated games
 main-available-games = Available games
+
+main-broken-integrations        = Broken integrations
+main-broken-integrations-retry  = Retry
+main-broken-integrations-remove = Remove
+
+game-card-dev = dev
+
+main-offline-banner = Network is not available. Displayed information may be outdated
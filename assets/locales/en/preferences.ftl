@@ -62,3 +62,9 @@ components-dxvk-latest = Latest
 
 components-install-corefonts = Install corefonts
 components-install-corefonts-description = Install default windows fonts to the wine prefix
+
+components-incompatible = Not compatible with some installed games
+
+incompatible-components   = Incompatible components
+compatibility-unsupported = {$component} {$version} is not supported by the game
+compatibility-blacklisted = {$component} {$version} is known to break the game
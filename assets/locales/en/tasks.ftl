@@ -19,6 +19,7 @@ tasks-post-transition-code = Running task post-transition code...
 tasks-creating-prefix      = Creating prefix...
 tasks-installing-dxvk      = Installing DXVK...
 tasks-installing-fonts     = Intalling fonts...
+tasks-applying-requirements = Applying game requirements...
 tasks-verifying-files      = Verifying files...
 tasks-repairing-files      = Repairing files...
 tasks-finished             = Finished
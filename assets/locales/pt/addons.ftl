@@ -1,4 +1,5 @@
 game-addons = Complementos
+game-settings = Configurações do jogo
 
 addon-install = Instalar
 addon-uninstall = Desinstalar
@@ -1,9 +1,15 @@
 details-developer = Desenvolvedor(a): {$developer}
 details-played = Jogado: {$played}
 details-last-played = Jogado pela última vez: {$last-played}
+details-tags = Tags: {$tags}
+
+details-homepage = Página inicial
+details-support = Suporte
 
 details-play = Jogar
 details-kill = Matar processo
 details-verify = Verificar
 details-manage-addons = Gerenciar complementos
 details-download = Baixar
+
+details-news = Notícias
@@ -1,7 +1,10 @@
 dialog-toast-details = Detalhes
+dialog-error-trace   = Rastreamento do erro
 
 dialog-stop     = Parar
 dialog-disable  = Desativar
 dialog-continue = Continuar
 dialog-close    = Fechar
 dialog-save     = Salvar
+dialog-yes      = Sim
+dialog-no       = Não
@@ -20,10 +20,12 @@ game-get-paths-failed           = Erro ao checar o diretório de instalação de
 game-get-integrity-failed       = Foi impossível conseguir dados de integridade de {$game-title}
 game-get-status-failed          = Não foi possível conseguir o status de {$game-title}
 game-not-installed              = O título {$game-title} não está instalado
+game-integration-not-loaded     = A integração de {$game-title} não está carregada
 game-launch-failed              = Não foi possível iniciar {$game-title}
 game-kill-failed                = Não foi possível matar o processo de {$game-title}
 
 launch-required-addon-unavailable = O complemento {$addon-title} do grupo {$group-title} está indisponível ou desatualizado. Você pode iniciar o jogo sem ele ou permanecer com a versão antiga.
+launch-incompatible-components    = Os componentes selecionados podem não funcionar com {$game-title}. Você pode alterá-los nas preferências ou iniciar o jogo mesmo assim.
 
 loading-preparing-folders-failed     = Erro ao preparar os diretórios padrão
 loading-initializing-debug-failed    = Erro ao iniciar a saída de debugging
@@ -38,6 +40,22 @@ loading-checking-dxvk-version-failed = Erro ao detectar a versão do DXVK
 loading-checking-applied-dxvk-failed = Erro ao verificar versão do DXVK instalado
 loading-checking-games-addons-failed = Erro ao verificar os complementos do jogo
 
+loading-integrations-rejected = Algumas integrações foram rejeitadas devido à falha na verificação da assinatura
+loading-integrations-skipped  = Algumas integrações não são compatíveis com esta versão do launcher
+
 main-open-launcher-folder-failed = Falha ao abrir a pasta do launcher
 main-open-config-file-failed     = Falha ao abrir arquivo de configurações
 main-open-debug-file-failed      = Falha ao abrir o arquivo de debug
+details-open-news-failed         = Falha ao abrir o link da notícia
+details-open-link-failed         = Falha ao abrir o link
+
+main-broken-integrations-retry-failed  = Falha ao carregar a integração
+main-broken-integrations-remove-failed = Falha ao remover a integração
+main-reload-integration-failed         = Falha ao recarregar a integração {$integration}
+
+script-error-api-unavailable     = A API do jogo não está disponível. Tente novamente mais tarde
+script-error-network-unavailable = A rede não está disponível
+script-error-rate-limited        = Muitas solicitações à API do jogo. Tente novamente mais tarde
+script-error-invalid-response    = A API do jogo retornou uma resposta inesperada
+script-error-not-found           = Os dados do jogo solicitados não foram encontrados
+script-error-not-supported       = Esta ação não é suportada pela integração
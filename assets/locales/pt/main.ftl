@@ -7,3 +7,11 @@ main-installed-games = Jogos instalados
 main-queued-games    = Jogos na fila
 main-outdated-games  = Jogos desatualizados
 main-available-games = Jogos disponíveis
+
+main-broken-integrations        = Integrações quebradas
+main-broken-integrations-retry  = Tentar novamente
+main-broken-integrations-remove = Remover
+
+game-card-dev = dev
+
+main-offline-banner = A rede não está disponível. As informações exibidas podem estar desatualizadas
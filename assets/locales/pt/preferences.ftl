@@ -62,3 +62,9 @@ components-dxvk-latest = Mais recente
 
 components-install-corefonts = Instalar as fontes necessárias.
 components-install-corefonts-description = Instala as fontes padrão do Windows no prefixo do Wine.
+
+components-incompatible = Incompatível com alguns jogos instalados
+
+incompatible-components   = Componentes incompatíveis
+compatibility-unsupported = {$component} {$version} não é suportado pelo jogo
+compatibility-blacklisted = {$component} {$version} é conhecido por quebrar o jogo
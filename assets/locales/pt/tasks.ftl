@@ -19,6 +19,7 @@ tasks-post-transition-code = Rodando tarefa de pós-transição do código...
 tasks-creating-prefix      = Criando prefixo...
 tasks-installing-dxvk      = Instalando o DXVK...
 tasks-installing-fonts     = Intalando fontes...
+tasks-applying-requirements = Aplicando requisitos do jogo...
 tasks-verifying-files      = Verificando arquivos...
 tasks-repairing-files      = Reparando arquivos...
 tasks-finished             = Pronto
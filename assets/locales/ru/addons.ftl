@@ -1,4 +1,5 @@
 game-addons = Дополнения игры
+game-settings = Настройки игры
 
 addon-install = Установить
 addon-uninstall = Удалить
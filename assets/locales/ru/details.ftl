@@ -1,9 +1,15 @@
 details-developer = Разработчик: {$developer}
 details-played = Время в игре: {$played}
 details-last-played = Последний запуск: {$last-played}
+details-tags = Теги: {$tags}
+
+details-homepage = Сайт
+details-support = Поддержка
 
 details-play = Играть
 details-kill = Убить
 details-verify = Проверить
 details-manage-addons = Дополнения
 details-download = Установить
+
+details-news = Новости
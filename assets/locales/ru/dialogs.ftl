@@ -1,7 +1,10 @@
 dialog-toast-details = Подробнее
+dialog-error-trace   = Трассировка ошибки
 
 dialog-stop     = Остановить
 dialog-disable  = Отключить
 dialog-continue = Продолжить
 dialog-close    = Закрыть
 dialog-save     = Сохранить
+dialog-yes      = Да
+dialog-no       = Нет
@@ -20,10 +20,12 @@ game-get-paths-failed           = Не удалось получить пути
 game-get-integrity-failed       = Не удалось получить информацию для проверки файлов {$game-title}
 game-get-status-failed          = Не удалось получить статус {$game-title}
 game-not-installed              = {$game-title} не установлен
+game-integration-not-loaded     = Интеграция {$game-title} не загружена
 game-launch-failed              = Не удалось запустить {$game-title}
 game-kill-failed                = Не удалось убить процесс {$game-title}
 
 launch-required-addon-unavailable = Дополнение {$addon-title} из группы {$group-title} недоступно или устарело. Вы можете запустить игру без него или продолжить использовать старую версию
+launch-incompatible-components    = Выбранные компоненты могут не работать с {$game-title}. Вы можете изменить их в настройках или всё равно запустить игру
 
 loading-preparing-folders-failed     = Не удалось подготовить папки по умолчанию
 loading-initializing-debug-failed    = Не удалось инициализировать отладочный вывод
@@ -38,6 +40,22 @@ loading-checking-dxvk-version-failed = Не удалось проверить в
 loading-checking-applied-dxvk-failed = Не удалось проверить установленную версию DXVK
 loading-checking-games-addons-failed = Не удалось проверить дополнения игр
 
+loading-integrations-rejected = Некоторые интеграции были отклонены из-за ошибки проверки подписи
+loading-integrations-skipped  = Некоторые интеграции несовместимы с этой версией лаунчера
+
 main-open-launcher-folder-failed = Не удалось открыть папку лаунчера
 main-open-config-file-failed     = Не удалось открыть файл настроек
 main-open-debug-file-failed      = Не удалось открыть файл отладки
+details-open-news-failed         = Не удалось открыть ссылку новости
+details-open-link-failed         = Не удалось открыть ссылку
+
+main-broken-integrations-retry-failed  = Не удалось загрузить интеграцию
+main-broken-integrations-remove-failed = Не удалось удалить интеграцию
+main-reload-integration-failed         = Не удалось перезагрузить интеграцию {$integration}
+
+script-error-api-unavailable     = API игры недоступно. Попробуйте позже
+script-error-network-unavailable = Сеть недоступна
+script-error-rate-limited        = Слишком много запросов к API игры. Попробуйте позже
+script-error-invalid-response    = API игры вернуло неожиданный ответ
+script-error-not-found           = Запрошенные данные игры не найдены
+script-error-not-supported       = Это действие не поддерживается интеграцией
@@ -7,3 +7,11 @@ main-installed-games = Установленные игры
 main-queued-games    = Игры в очереди
 main-outdated-games  = Устаревшие игры
 main-available-games = Доступные игры
+
+main-broken-integrations        = Сломанные интеграции
+main-broken-integrations-retry  = Повторить
+main-broken-integrations-remove = Удалить
+
+game-card-dev = dev
+
+main-offline-banner = Сеть недоступна. Отображаемая информация может быть устаревшей
@@ -62,3 +62,9 @@ components-dxvk-latest = Последняя
 
 components-install-corefonts = Устанавливать шрифты Windows
 components-install-corefonts-description = Устанавливать стандартные шрифты Windows при создании префикса Wine
+
+components-incompatible = Несовместимо с некоторыми установленными играми
+
+incompatible-components   = Несовместимые компоненты
+compatibility-unsupported = {$component} {$version} не поддерживается игрой
+compatibility-blacklisted = {$component} {$version} ломает игру
@@ -15,6 +15,7 @@ tasks-post-transition-code = Выполнение обработчика пос
 tasks-creating-prefix      = Создание префикса...
 tasks-installing-dxvk      = Установка DXVK...
 tasks-installing-fonts     = Установка шрифтов...
+tasks-applying-requirements = Применение требований игры...
 tasks-verifying-files      = Проверка файлов...
 tasks-repairing-files      = Восстановление файлов...
 tasks-finished             = Завершена
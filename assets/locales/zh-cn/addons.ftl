@@ -1,4 +1,5 @@
 game-addons = 语言文件
+game-settings = 游戏设置
 
 addon-install = 安装
 addon-uninstall = 卸载
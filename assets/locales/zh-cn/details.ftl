@@ -1,9 +1,15 @@
 details-developer = 开发者: {$developer}
 details-played = 游玩时长: {$played}
 details-last-played = 上次游玩: {$last-played}
+details-tags = 标签: {$tags}
+
+details-homepage = 主页
+details-support = 支持
 
 details-play = 启动
 details-kill = 杀死进程
 details-verify = 验证
 details-manage-addons = 管理拓展
 details-download = 下载
+
+details-news = 新闻
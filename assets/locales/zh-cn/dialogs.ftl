@@ -1,7 +1,10 @@
 dialog-toast-details = Details
+dialog-error-trace   = 错误跟踪
 
 dialog-stop     = 停止
 dialog-disable  = 关闭
 dialog-continue = 继续
 dialog-close    = 关闭
 dialog-save     = 保存
+dialog-yes      = 是
+dialog-no       = 否
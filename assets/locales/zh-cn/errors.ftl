@@ -20,10 +20,12 @@ game-get-paths-failed           = 无法获取 {$game-title} 的路径
 game-get-integrity-failed       = 无法获取 {$game-title} integrity info
 game-get-status-failed          = Unable to get {$game-title} status
 game-not-installed              = {$game-title} 未安装
+game-integration-not-loaded     = {$game-title} integration is not loaded
 game-launch-failed              = Failed to launch {$game-title}
 game-kill-failed                = Failed to kill {$game-title}
 
 launch-required-addon-unavailable = Addon {$addon-title} from group {$group-title} is unavailable or outdated. You can launch the game without it or continue to use old version
+launch-incompatible-components    = 所选组件可能无法与 {$game-title} 一起使用。您可以在设置中更改它们，或仍然启动游戏
 
 loading-preparing-folders-failed     = Failed to prepare default folders
 loading-initializing-debug-failed    = Failed to initialize debug output
@@ -38,6 +40,22 @@ loading-checking-dxvk-version-failed = 无法检查 DXVK 版本
 loading-checking-applied-dxvk-failed = Failed to check applied dxvk version
 loading-checking-games-addons-failed = Failed to check games addons
 
+loading-integrations-rejected = 部分集成因签名验证失败而被拒绝
+loading-integrations-skipped  = 部分集成与当前启动器版本不兼容
+
 main-open-launcher-folder-failed = Failed to open launcher folder
 main-open-config-file-failed     = Failed to open config file
 main-open-debug-file-failed      = Failed to open debug file
+details-open-news-failed         = Failed to open news link
+details-open-link-failed         = Failed to open link
+
+main-broken-integrations-retry-failed  = 加载集成失败
+main-broken-integrations-remove-failed = 移除集成失败
+main-reload-integration-failed         = 重新加载集成 {$integration} 失败
+
+script-error-api-unavailable     = 游戏 API 不可用，请稍后再试
+script-error-network-unavailable = 网络不可用
+script-error-rate-limited        = 对游戏 API 的请求过多，请稍后再试
+script-error-invalid-response    = 游戏 API 返回了意外的响应
+script-error-not-found           = 未找到请求的游戏数据
+script-error-not-supported       = 集成不支持此操作
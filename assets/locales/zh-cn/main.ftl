@@ -7,3 +7,11 @@ main-installed-games = Installed games
 main-queued-games    = Queued games
 main-outdated-games  = Outdated games
 main-available-games = Available games
+
+main-broken-integrations        = 损坏的集成
+main-broken-integrations-retry  = 重试
+main-broken-integrations-remove = 移除
+
+game-card-dev = 开发
+
+main-offline-banner = 网络不可用，显示的信息可能已过时
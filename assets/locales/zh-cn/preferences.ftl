@@ -62,3 +62,9 @@ components-dxvk-latest = 最新
 
 components-install-corefonts = 安装 corefonts
 components-install-corefonts-description = 将默认的 Windows 字体安装到 wine prefix
+
+components-incompatible = 与部分已安装的游戏不兼容
+
+incompatible-components   = 组件不兼容
+compatibility-unsupported = 游戏不支持 {$component} {$version}
+compatibility-blacklisted = 已知 {$component} {$version} 会导致游戏出现问题
@@ -19,6 +19,7 @@ tasks-post-transition-code = 运行任务转换后代码...
 tasks-creating-prefix      = 正在创建 prefix...
 tasks-installing-dxvk      = 正在安装 DXVK...
 tasks-installing-fonts     = 正在安装 fonts...
+tasks-applying-requirements = 正在应用游戏需求...
 tasks-verifying-files      = 正在验证下载好的文件...
 tasks-repairing-files      = 正在修复文件...
 tasks-finished             = 完成
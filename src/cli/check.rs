@@ -0,0 +1,330 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value as Json};
+
+use mlua::Error as LuaError;
+
+use crate::games::integrations::Game;
+use crate::games::integrations::manifest::Manifest;
+use crate::games::integrations::limits::LimitError;
+use crate::games::integrations::standards::error::ScriptError;
+
+use crate::APP_VERSION;
+use crate::LAUNCHER_FOLDER;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// Function returned correct value
+    Passed,
+
+    /// Optional function is not implemented
+    Skipped,
+
+    /// Required function is not implemented
+    Missing,
+
+    /// Function returned value of a wrong type or structure
+    WrongType(String),
+
+    /// Function has thrown an error or exceeded its limits
+    Thrown(String)
+}
+
+impl CheckStatus {
+    #[inline]
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Missing | Self::WrongType(_) | Self::Thrown(_))
+    }
+
+    #[inline]
+    pub fn to_json(&self) -> Json {
+        match self {
+            Self::Passed  => json!({ "status": "passed" }),
+            Self::Skipped => json!({ "status": "skipped" }),
+            Self::Missing => json!({ "status": "missing" }),
+
+            Self::WrongType(error) => json!({ "status": "wrong_type", "error": error }),
+            Self::Thrown(error)    => json!({ "status": "thrown", "error": error })
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    /// Full name of the function, e.g. `v1_game_get_status`
+    pub function: String,
+
+    /// Arguments the function was called with
+    pub context: Vec<String>,
+
+    pub status: CheckStatus
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub game_name: String,
+    pub results: Vec<CheckResult>
+}
+
+impl Report {
+    /// Check if all the functions passed validation
+    #[inline]
+    pub fn is_passed(&self) -> bool {
+        !self.results.iter().any(|result| result.status.is_failed())
+    }
+
+    pub fn to_json(&self) -> Json {
+        json!({
+            "game": self.game_name,
+            "passed": self.is_passed(),
+            "results": self.results.iter().map(|result| {
+                let mut value = result.status.to_json();
+
+                value["function"] = json!(result.function);
+                value["context"] = json!(result.context);
+
+                value
+            }).collect::<Vec<_>>()
+        })
+    }
+
+    pub fn print(&self) {
+        println!("Checking {} integration", self.game_name);
+        println!();
+
+        for result in &self.results {
+            let context = if result.context.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", result.context.join(", "))
+            };
+
+            match &result.status {
+                CheckStatus::Passed  => println!("[ OK ] {}{context}", result.function),
+                CheckStatus::Skipped => println!("[SKIP] {}{context}: not implemented", result.function),
+                CheckStatus::Missing => println!("[MISS] {}{context}: required function is not implemented", result.function),
+
+                CheckStatus::WrongType(error) => println!("[TYPE] {}{context}: {error}", result.function),
+                CheckStatus::Thrown(error)    => println!("[FAIL] {}{context}: {error}", result.function)
+            }
+        }
+
+        let failed = self.results.iter()
+            .filter(|result| result.status.is_failed())
+            .count();
+
+        println!();
+        println!("{} checks, {failed} failed", self.results.len());
+    }
+}
+
+/// Classify error returned by the driver
+fn get_error_status(err: anyhow::Error) -> CheckStatus {
+    if err.is::<LimitError>() || err.is::<ScriptError>() {
+        return CheckStatus::Thrown(err.to_string());
+    }
+
+    match err.downcast_ref::<LuaError>() {
+        // Returned lua value can't be converted to the expected type
+        Some(LuaError::FromLuaConversionError { .. }) => CheckStatus::WrongType(err.to_string()),
+
+        Some(_) => CheckStatus::Thrown(err.to_string()),
+
+        // Other errors are returned by the `from_table` parsers
+        None => CheckStatus::WrongType(err.to_string())
+    }
+}
+
+struct Checker<'a> {
+    game: &'a Game,
+    results: Vec<CheckResult>
+}
+
+impl<'a> Checker<'a> {
+    /// Call the function if it's implemented and validate its output
+    fn check<T>(&mut self, function: &str, required: bool, context: &[&str], call: impl FnOnce(&Game) -> anyhow::Result<T>) -> Option<T> {
+        let implemented = match self.game.driver.has_function(function) {
+            Ok(implemented) => implemented,
+            Err(err) => {
+                self.push(function, context, get_error_status(err));
+
+                return None;
+            }
+        };
+
+        if !implemented {
+            self.push(function, context, if required {
+                CheckStatus::Missing
+            } else {
+                CheckStatus::Skipped
+            });
+
+            return None;
+        }
+
+        match call(self.game) {
+            Ok(value) => {
+                self.push(function, context, CheckStatus::Passed);
+
+                Some(value)
+            }
+
+            Err(err) => {
+                self.push(function, context, get_error_status(err));
+
+                None
+            }
+        }
+    }
+
+    /// Check only that the function is implemented, without calling it
+    fn check_exists(&mut self, function: &str, required: bool) {
+        self.check(function, required, &[], |_| Ok(()));
+    }
+
+    fn push(&mut self, function: &str, context: &[&str], status: CheckStatus) {
+        self.results.push(CheckResult {
+            function: self.game.driver.standard.function_name(function),
+            context: context.iter().map(|value| value.to_string()).collect(),
+            status
+        });
+    }
+}
+
+/// Create empty folder for the checks
+fn create_folder(path: PathBuf) -> anyhow::Result<String> {
+    std::fs::create_dir_all(&path)?;
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Load integration from the folder and validate all its functions
+///
+/// Functions are called against empty game folders, so they should be run
+/// with a temporary `LAUNCHER_FOLDER` to not affect installed games
+pub fn run(path: &Path) -> anyhow::Result<Report> {
+    let manifest_path = path.join("manifest.json");
+    let manifest = Manifest::from_file(&manifest_path)?;
+
+    manifest.check_launcher_version(APP_VERSION)?;
+
+    let game = Game::from_manifest(manifest, manifest_path)?;
+
+    let mut checker = Checker {
+        game: &game,
+        results: Vec::new()
+    };
+
+    let editions = checker.check("game_get_editions_list", true, &[], |game| game.driver.get_game_editions_list())
+        .unwrap_or_default();
+
+    for edition in editions {
+        let edition = edition.name.as_str();
+
+        let root = LAUNCHER_FOLDER.join("games")
+            .join(&game.manifest.game_name)
+            .join(edition);
+
+        let game_path = create_folder(root.join("game"))?;
+        let addons_path = create_folder(root.join("addons"))?;
+        let transition_path = create_folder(root.join("transition"))?;
+
+        let game_path = game_path.as_str();
+        let addons_path = addons_path.as_str();
+        let transition_path = transition_path.as_str();
+
+        // Visual
+
+        checker.check("visual_get_card_picture", true, &[edition], |game| game.driver.get_card_picture(edition));
+        checker.check("visual_get_background_picture", true, &[edition], |game| game.driver.get_background_picture(edition));
+        checker.check("visual_get_details_background_css", false, &[edition], |game| game.driver.get_details_background_style(edition));
+        checker.check("game_get_news", false, &[edition], |game| game.driver.get_news(edition, "en-us"));
+
+        // Game
+
+        checker.check("game_is_installed", true, &[edition], |game| game.driver.is_game_installed(game_path, edition));
+        checker.check("game_get_version", true, &[edition], |game| game.driver.get_game_version(game_path, edition));
+        checker.check("game_get_download", true, &[edition], |game| game.driver.get_game_download(edition));
+        checker.check("game_get_diff", true, &[edition], |game| game.driver.get_game_diff(game_path, edition));
+        checker.check("game_get_status", true, &[edition], |game| game.driver.get_game_status(game_path, edition));
+        checker.check("game_get_launch_options", true, &[edition], |game| game.driver.get_launch_options(game_path, addons_path, edition));
+        checker.check("game_is_running", true, &[edition], |game| game.driver.is_process_running(game_path, edition));
+        checker.check("game_get_integrity_info", true, &[edition], |game| game.driver.get_game_integrity(game_path, edition));
+        checker.check("game_get_settings_schema", false, &[edition], |game| game.driver.get_settings_schema(edition));
+        checker.check("game_get_prefix_requirements", false, &[edition], |game| game.driver.get_prefix_requirements(edition));
+        checker.check("game_get_compatibility", false, &[edition], |game| game.driver.get_compatibility(edition));
+
+        checker.check("game_diff_pre_transition", false, &[edition], |game| game.driver.run_game_diff_pre_transition(game_path, edition));
+        checker.check("game_diff_transition", false, &[edition], |game| game.driver.run_game_diff_transition(transition_path, edition));
+        checker.check("game_diff_post_transition", false, &[edition], |game| game.driver.run_game_diff_post_transition(game_path, edition));
+
+        checker.check("game_pre_launch", false, &[edition], |game| game.driver.run_game_pre_launch(game_path, addons_path, edition));
+        checker.check("game_post_exit", false, &[edition], |game| game.driver.run_game_post_exit(game_path, addons_path, edition));
+
+        // Addons
+
+        let groups = checker.check("addons_get_list", true, &[edition], |game| game.driver.get_addons_list(edition))
+            .unwrap_or_default();
+
+        for group in groups {
+            for addon in group.addons {
+                let addon_path = create_folder(PathBuf::from(addons_path).join(&group.name).join(&addon.name))?;
+                let addon_path = addon_path.as_str();
+
+                let group = group.name.as_str();
+                let addon = addon.name.as_str();
+
+                let context = [edition, group, addon];
+
+                checker.check("addons_is_installed", true, &context, |game| game.driver.is_addon_installed(group, addon, addon_path, edition));
+                checker.check("addons_get_version", true, &context, |game| game.driver.get_addon_version(group, addon, addon_path, edition));
+                checker.check("addons_get_download", true, &context, |game| game.driver.get_addon_download(group, addon, edition));
+                checker.check("addons_get_diff", true, &context, |game| game.driver.get_addon_diff(group, addon, addon_path, edition));
+                checker.check("addons_get_paths", true, &context, |game| game.driver.get_addon_paths(group, addon, addon_path, edition));
+                checker.check("addons_get_integrity_info", true, &context, |game| game.driver.get_addon_integrity(group, addon, addon_path, edition));
+
+                checker.check("addons_diff_pre_transition", false, &context, |game| game.driver.run_addons_diff_pre_transition(group, addon, addon_path, edition));
+                checker.check("addons_diff_transition", false, &context, |game| game.driver.run_addons_diff_transition(group, addon, transition_path, edition));
+                checker.check("addons_diff_post_transition", false, &context, |game| game.driver.run_addons_diff_post_transition(group, addon, addon_path, edition));
+            }
+        }
+    }
+
+    // Killing processes can affect games running outside of the check,
+    // and custom hashes require algorithms known only to the integration
+    checker.check_exists("game_kill", true);
+    checker.check_exists("integrity_hash", false);
+
+    Ok(Report {
+        game_name: game.manifest.game_name.clone(),
+        results: checker.results
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_error_is_thrown() {
+        let err = anyhow::Error::new(ScriptError {
+            code: String::from("api-unavailable"),
+            message: String::from("API is unavailable"),
+            details: None,
+            trace: String::new()
+        });
+
+        assert!(matches!(get_error_status(err), CheckStatus::Thrown(_)));
+    }
+
+    #[test]
+    fn conversion_error_is_wrong_type() {
+        let err = anyhow::Error::new(LuaError::FromLuaConversionError {
+            from: "string",
+            to: "table",
+            message: None
+        });
+
+        assert!(matches!(get_error_status(err), CheckStatus::WrongType(_)));
+    }
+}
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+pub mod check;
+
+const USAGE: &str = "Usage: anime-games-launcher integration check <path> [--json]";
+
+/// Run command line command without starting the GUI
+///
+/// Return `None` if no command was given, or the command's exit code
+pub fn run(args: &[String]) -> Option<i32> {
+    let args = args.iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+
+    match args.as_slice() {
+        ["integration", "check", path, flags @ ..] => {
+            let json = flags.contains(&"--json");
+
+            let path = match PathBuf::from(path).canonicalize() {
+                Ok(path) => path,
+                Err(err) => {
+                    eprintln!("Failed to open integration folder {path}: {err}");
+
+                    return Some(2);
+                }
+            };
+
+            // Use temporary launcher folder so the check doesn't affect
+            // installed games and the launcher's config
+            let launcher_folder = std::env::temp_dir()
+                .join(format!("anime-games-launcher-check-{}", std::process::id()));
+
+            std::env::set_var("LAUNCHER_FOLDER", &launcher_folder);
+
+            let result = check::run(&path);
+
+            if launcher_folder.exists() {
+                if let Err(err) = std::fs::remove_dir_all(&launcher_folder) {
+                    eprintln!("Failed to remove temporary folder {launcher_folder:?}: {err}");
+                }
+            }
+
+            match result {
+                Ok(report) => {
+                    if json {
+                        println!("{:#}", report.to_json());
+                    }
+
+                    else {
+                        report.print();
+                    }
+
+                    Some(if report.is_passed() { 0 } else { 1 })
+                }
+
+                Err(err) => {
+                    eprintln!("Failed to load integration: {err}");
+
+                    Some(1)
+                }
+            }
+        }
+
+        ["integration", ..] => {
+            eprintln!("{USAGE}");
+
+            Some(2)
+        }
+
+        _ => None
+    }
+}
@@ -0,0 +1,102 @@
+use crate::tr;
+use crate::config;
+
+use crate::games::integrations::Game;
+use crate::games::integrations::standards::prelude::*;
+use crate::games::integrations::standards::game::StatusSeverity;
+
+use super::wine::Wine;
+use super::dxvk::Dxvk;
+
+/// Compatibility issues of the selected components with the game edition
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentsIssues {
+    pub wine: Option<CompatibilityIssue>,
+    pub dxvk: Option<CompatibilityIssue>
+}
+
+impl ComponentsIssues {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.wine.is_none() && self.dxvk.is_none()
+    }
+
+    /// Get game status with the most severe issue and descriptions of all of them
+    pub fn to_status(&self, wine: &Wine, dxvk: &Dxvk) -> Option<GameStatus> {
+        let issues = [
+            self.wine.as_ref().map(|issue| (issue, get_wine_reason(issue, wine))),
+            self.dxvk.as_ref().map(|issue| (issue, get_dxvk_reason(issue, dxvk)))
+        ];
+
+        let issues = issues.into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        if issues.is_empty() {
+            return None;
+        }
+
+        let severity = if issues.iter().any(|(issue, _)| issue.severity() == StatusSeverity::Critical) {
+            StatusSeverity::Critical
+        } else {
+            StatusSeverity::Warning
+        };
+
+        let reason = issues.into_iter()
+            .map(|(_, reason)| reason)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(GameStatus {
+            allow_launch: true,
+            severity,
+            reason: Some(reason)
+        })
+    }
+}
+
+fn get_reason(component: &str, version: &str, issue: &CompatibilityIssue) -> String {
+    match issue {
+        CompatibilityIssue::Unsupported => tr!("compatibility-unsupported", {
+            "component" = component,
+            "version" = version
+        }),
+
+        CompatibilityIssue::Blacklisted { reason } => {
+            let message = tr!("compatibility-blacklisted", {
+                "component" = component,
+                "version" = version
+            });
+
+            match reason {
+                Some(reason) => format!("{message}: {reason}"),
+                None => message
+            }
+        }
+    }
+}
+
+#[inline]
+pub fn get_wine_reason(issue: &CompatibilityIssue, wine: &Wine) -> String {
+    get_reason("Wine", &wine.title, issue)
+}
+
+#[inline]
+pub fn get_dxvk_reason(issue: &CompatibilityIssue, dxvk: &Dxvk) -> String {
+    get_reason("DXVK", &dxvk.name, issue)
+}
+
+/// Check given components against the game edition's compatibility rules
+///
+/// Wine is matched by its name and DXVK by its version,
+/// builds are taken from the config
+pub fn check(game: &Game, edition: &str, wine: &Wine, dxvk: &Dxvk) -> anyhow::Result<ComponentsIssues> {
+    let config = config::get();
+
+    let compatibility = game.driver.get_compatibility(edition)?;
+
+    Ok(ComponentsIssues {
+        wine: compatibility.wine.check(&config.components.wine.build, &wine.name),
+        dxvk: compatibility.dxvk.check(&config.components.dxvk.build, &dxvk.version)
+    })
+}
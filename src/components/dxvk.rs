@@ -13,12 +13,15 @@ use anime_game_core::updater::UpdaterExt;
 
 use crate::{
     config,
+    offline,
     COMPONENTS_FOLDER
 };
 
 use crate::components::{
     Updater,
-    Status
+    Status,
+    compare_versions,
+    get_versions_cache
 };
 
 use crate::ui::components::game_card::CardInfo;
@@ -37,11 +40,85 @@ pub struct Dxvk {
 impl Dxvk {
     /// Get selected wine build versions list
     pub fn versions() -> anyhow::Result<Vec<Self>> {
+        if offline::is_offline() {
+            return Self::installed_versions();
+        }
+
+        match Self::remote_versions() {
+            Ok(versions) => Ok(versions),
+
+            Err(err) if offline::is_network_error(&err) => {
+                offline::enable(&err);
+
+                Self::installed_versions()
+            }
+
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get dxvk versions installed in the components folder
+    /// 
+    /// Only versions of the selected build are returned if its versions list
+    /// was cached before. Versions are sorted from the newest to the oldest
+    pub fn installed_versions() -> anyhow::Result<Vec<Self>> {
+        let path = COMPONENTS_FOLDER.join("dxvk");
+
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let build = config::get().components.dxvk.build;
+
+        let cache = std::fs::read(get_versions_cache("dxvk", &build)).ok()
+            .and_then(|versions| serde_json::from_slice::<Vec<Json>>(&versions).ok());
+
+        if let Some(versions) = cache {
+            return Ok(Self::parse_versions(versions).into_iter()
+                .filter(Self::is_downloaded)
+                .collect());
+        }
+
+        let mut versions = Vec::new();
+
+        for entry in path.read_dir()?.flatten() {
+            if entry.path().is_dir() {
+                let name = entry.file_name().to_string_lossy().to_string();
+
+                versions.push(Self {
+                    title: name.clone(),
+                    version: name.strip_prefix("dxvk-").unwrap_or(&name).to_string(),
+                    uri: String::new(),
+                    name
+                });
+            }
+        }
+
+        versions.sort_by(|a, b| compare_versions(&b.version, &a.version));
+
+        Ok(versions)
+    }
+
+    /// Fetch selected dxvk build versions list from the components channel
+    fn remote_versions() -> anyhow::Result<Vec<Self>> {
         let components = config::get().components;
 
         let dxvk_versions = minreq::get(format!("{}/dxvk/{}.json", &components.channel, &components.dxvk.build))
             .send()?.json::<Vec<Json>>()?;
 
+        let cache = get_versions_cache("dxvk", &components.dxvk.build);
+
+        let result = std::fs::create_dir_all(COMPONENTS_FOLDER.join("dxvk"))
+            .and_then(|_| std::fs::write(&cache, serde_json::to_vec(&dxvk_versions)?));
+
+        if let Err(err) = result {
+            tracing::warn!("Failed to cache dxvk versions list: {err}");
+        }
+
+        Ok(Self::parse_versions(dxvk_versions))
+    }
+
+    fn parse_versions(dxvk_versions: Vec<Json>) -> Vec<Self> {
         let mut versions = Vec::new();
 
         for dxvk in dxvk_versions {
@@ -60,7 +137,7 @@ impl Dxvk {
             }
         }
 
-        Ok(versions)
+        versions
     }
 
     /// Resolve component version from the config file
@@ -1,4 +1,6 @@
 use std::cell::Cell;
+use std::cmp::Ordering;
+use std::path::PathBuf;
 use std::thread::JoinHandle;
 
 use anime_game_core::updater::UpdaterExt;
@@ -6,8 +8,37 @@ use anime_game_core::updater::UpdaterExt;
 use crate::ui::components::tasks_queue::{ResolvedTask, TaskStatus};
 use crate::ui::components::game_card::CardInfo;
 
+use crate::COMPONENTS_FOLDER;
+
 pub mod wine;
 pub mod dxvk;
+pub mod prefix;
+pub mod compatibility;
+
+/// Compare component names by their numeric parts
+///
+/// Names are compared as versions, so `wine-9.0` is lower than `wine-10.0`
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parse = |name: &str| -> Vec<u64> {
+        name.split(|char: char| !char.is_ascii_digit())
+            .filter(|part| !part.is_empty())
+            .map(|part| part.parse::<u64>().unwrap_or(0))
+            .collect()
+    };
+
+    parse(a).cmp(&parse(b))
+}
+
+/// Get path to the cached versions list of the component build
+///
+/// Lists are cached when fetched from the components channel,
+/// so installed components can be matched with their builds offline
+#[inline]
+pub fn get_versions_cache(component: &str, build: &str) -> PathBuf {
+    COMPONENTS_FOLDER
+        .join(component)
+        .join(format!("{build}.json"))
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use wincompatlib::prelude::*;
+
+use crate::games;
+
+use crate::components::wine::Wine as WineComponent;
+use crate::games::integrations::standards::prelude::*;
+use crate::games::integrations::standards::prefix::{get_font, is_valid_verb};
+
+/// Registry key of the wine DLL overrides
+const DLL_OVERRIDES_KEY: &str = "HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides";
+
+/// Get prefix requirements of all the installed game editions
+///
+/// Games which failed to report their requirements are skipped
+pub fn get_installed_requirements() -> anyhow::Result<PrefixRequirements> {
+    let mut requirements = PrefixRequirements::default();
+
+    for (game, edition) in games::installed_editions()? {
+        match game.driver.get_prefix_requirements(&edition.name) {
+            Ok(edition_requirements) => requirements.merge(edition_requirements),
+
+            Err(err) => tracing::warn!(game = %game.manifest.game_name, edition = %edition.name, "Failed to get prefix requirements: {err}")
+        }
+    }
+
+    Ok(requirements)
+}
+
+/// Read DLL overrides from the prefix registry
+///
+/// Names are lowercased since wine treats them case insensitively
+fn get_dll_overrides(prefix: &Path) -> HashMap<String, String> {
+    let Ok(registry) = std::fs::read_to_string(prefix.join("user.reg")) else {
+        return HashMap::new();
+    };
+
+    let mut overrides = HashMap::new();
+    let mut is_overrides_section = false;
+
+    for line in registry.lines() {
+        if line.starts_with('[') {
+            is_overrides_section = line.starts_with("[Software\\\\Wine\\\\DllOverrides]");
+        }
+
+        // "d3dcompiler_47"="native,builtin"
+        else if is_overrides_section {
+            if let Some((name, mode)) = line.split_once("\"=\"") {
+                overrides.insert(
+                    name.trim_start_matches('"').to_ascii_lowercase(),
+                    mode.trim_end_matches('"').to_string()
+                );
+            }
+        }
+    }
+
+    overrides
+}
+
+/// Read winetricks verbs installed to the prefix
+fn get_verbs(prefix: &Path) -> Vec<String> {
+    std::fs::read_to_string(prefix.join("winetricks.log"))
+        .map(|log| log.lines().map(|verb| verb.trim().to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Get requirements which are not yet applied to the prefix
+pub fn get_missing(prefix: &Path, requirements: &PrefixRequirements) -> PrefixRequirements {
+    let overrides = get_dll_overrides(prefix);
+    let verbs = get_verbs(prefix);
+
+    PrefixRequirements {
+        fonts: requirements.fonts.iter()
+            .filter(|name| !get_font(name).map(|font| font.is_installed(prefix)).unwrap_or(false))
+            .cloned()
+            .collect(),
+
+        dll_overrides: requirements.dll_overrides.iter()
+            .filter(|(name, mode)| overrides.get(&name.to_ascii_lowercase()) != Some(*mode))
+            .map(|(name, mode)| (name.clone(), mode.clone()))
+            .collect(),
+
+        verbs: requirements.verbs.iter()
+            .filter(|verb| !verbs.contains(verb))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Install core font to the prefix of the given wine
+pub fn install_font(wine: &Wine, name: &str) -> anyhow::Result<()> {
+    let Some(font) = get_font(name) else {
+        anyhow::bail!("Unknown font: {name}");
+    };
+
+    wine.install_font(font)?;
+
+    Ok(())
+}
+
+/// Add DLL override to the prefix registry
+pub fn install_dll_override(wine_binary: &Path, prefix: &Path, name: &str, mode: &str) -> anyhow::Result<()> {
+    let status = Command::new(wine_binary)
+        .args(["reg", "add", DLL_OVERRIDES_KEY, "/v", name, "/d", mode, "/f"])
+        .env("WINEARCH", "win64")
+        .env("WINEPREFIX", prefix)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+
+    if !status.success() {
+        anyhow::bail!("Failed to add {name} DLL override: {status}");
+    }
+
+    Ok(())
+}
+
+/// Install winetricks verb to the prefix
+///
+/// Winetricks must be available in the system
+pub fn install_verb(wine_binary: &Path, prefix: &Path, verb: &str) -> anyhow::Result<()> {
+    if !is_valid_verb(verb) {
+        anyhow::bail!("Invalid winetricks verb: {verb}");
+    }
+
+    let status = Command::new("winetricks")
+        .args(["-q", verb])
+        .env("WINE", wine_binary)
+        .env("WINEARCH", "win64")
+        .env("WINEPREFIX", prefix)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|err| anyhow::anyhow!("Failed to run winetricks: {err}"))?;
+
+    if !status.success() {
+        anyhow::bail!("Failed to install {verb} winetricks verb: {status}");
+    }
+
+    Ok(())
+}
+
+/// Apply all the given requirements to the prefix
+pub fn apply(wine: &WineComponent, prefix: &Path, requirements: &PrefixRequirements) -> anyhow::Result<()> {
+    let wine_binary = wine.get_executable();
+
+    if !requirements.fonts.is_empty() {
+        let Some(wincompatlib) = wine.to_wincompatlib() else {
+            anyhow::bail!("Failed to resolve wincompatlib wine descriptor");
+        };
+
+        let wincompatlib = wincompatlib.with_prefix(prefix);
+
+        for font in &requirements.fonts {
+            install_font(&wincompatlib, font)?;
+        }
+    }
+
+    for (name, mode) in &requirements.dll_overrides {
+        install_dll_override(&wine_binary, prefix, name, mode)?;
+    }
+
+    for verb in &requirements.verbs {
+        install_verb(&wine_binary, prefix, verb)?;
+    }
+
+    Ok(())
+}
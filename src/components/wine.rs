@@ -24,12 +24,15 @@ use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask};
 
 use crate::{
     config,
+    offline,
     COMPONENTS_FOLDER
 };
 
 use crate::components::{
     Updater,
-    Status
+    Status,
+    compare_versions,
+    get_versions_cache
 };
 
 use super::DownloadComponentResolvedTask;
@@ -44,11 +47,84 @@ pub struct Wine {
 impl Wine {
     /// Get selected wine build versions list
     pub fn versions() -> anyhow::Result<Vec<Self>> {
+        if offline::is_offline() {
+            return Self::installed_versions();
+        }
+
+        match Self::remote_versions() {
+            Ok(versions) => Ok(versions),
+
+            Err(err) if offline::is_network_error(&err) => {
+                offline::enable(&err);
+
+                Self::installed_versions()
+            }
+
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get wine versions installed in the components folder
+    /// 
+    /// Only versions of the selected build are returned if its versions list
+    /// was cached before. Versions are sorted from the newest to the oldest
+    pub fn installed_versions() -> anyhow::Result<Vec<Self>> {
+        let path = COMPONENTS_FOLDER.join("wine");
+
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let build = config::get().components.wine.build;
+
+        let cache = std::fs::read(get_versions_cache("wine", &build)).ok()
+            .and_then(|versions| serde_json::from_slice::<Vec<Json>>(&versions).ok());
+
+        if let Some(versions) = cache {
+            return Ok(Self::parse_versions(versions).into_iter()
+                .filter(Self::is_downloaded)
+                .collect());
+        }
+
+        let mut versions = Vec::new();
+
+        for entry in path.read_dir()?.flatten() {
+            if entry.path().is_dir() {
+                let name = entry.file_name().to_string_lossy().to_string();
+
+                versions.push(Self {
+                    title: name.clone(),
+                    uri: String::new(),
+                    name
+                });
+            }
+        }
+
+        versions.sort_by(|a, b| compare_versions(&b.name, &a.name));
+
+        Ok(versions)
+    }
+
+    /// Fetch selected wine build versions list from the components channel
+    fn remote_versions() -> anyhow::Result<Vec<Self>> {
         let components = config::get().components;
 
         let wine_versions = minreq::get(format!("{}/wine/{}.json", &components.channel, &components.wine.build))
             .send()?.json::<Vec<Json>>()?;
 
+        let cache = get_versions_cache("wine", &components.wine.build);
+
+        let result = std::fs::create_dir_all(COMPONENTS_FOLDER.join("wine"))
+            .and_then(|_| std::fs::write(&cache, serde_json::to_vec(&wine_versions)?));
+
+        if let Err(err) = result {
+            tracing::warn!("Failed to cache wine versions list: {err}");
+        }
+
+        Ok(Self::parse_versions(wine_versions))
+    }
+
+    fn parse_versions(wine_versions: Vec<Json>) -> Vec<Self> {
         let mut versions = Vec::new();
 
         for wine in wine_versions {
@@ -65,7 +141,7 @@ impl Wine {
             }
         }
 
-        Ok(versions)
+        versions
     }
 
     /// Resolve component version from the config file
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use serde::{Serialize, Deserialize};
@@ -9,7 +10,29 @@ use crate::LAUNCHER_FOLDER;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Integrations {
     pub sources: Vec<String>,
-    pub path: PathBuf
+    pub path: PathBuf,
+    pub limits: Limits,
+
+    /// Base64 encoded ed25519 public keys trusted for each source
+    pub trusted_keys: HashMap<String, Vec<String>>,
+
+    /// Accept integrations from sources without trusted keys
+    ///
+    /// Enabled by default until the official source's key is shipped,
+    /// otherwise fresh installations couldn't download any integration
+    pub allow_unsigned: bool,
+
+    /// Reload integration scripts when their files are changed
+    pub hot_reload: bool,
+
+    /// Record or replay network requests of integration scripts
+    pub fixtures: Fixtures,
+
+    /// Minimal level of integration scripts logs for each game
+    pub log_levels: HashMap<String, LogLevel>,
+
+    /// Max size of the integration script storage of each game edition, in kilobytes
+    pub storage_limit: u64
 }
 
 impl Default for Integrations {
@@ -26,7 +49,14 @@ impl Default for Integrations {
                 ]
             },
 
-            path: LAUNCHER_FOLDER.join("integrations")
+            path: LAUNCHER_FOLDER.join("integrations"),
+            limits: Limits::default(),
+            trusted_keys: HashMap::new(),
+            allow_unsigned: true,
+            hot_reload: false,
+            fixtures: Fixtures::default(),
+            log_levels: HashMap::new(),
+            storage_limit: 1024
         }
     }
 }
@@ -46,6 +76,191 @@ impl From<&Json> for Integrations {
                 )
                 .unwrap_or(default.sources),
 
+            path: value.get("path")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+                .unwrap_or(default.path),
+
+            limits: value.get("limits")
+                .map(Limits::from)
+                .unwrap_or(default.limits),
+
+            trusted_keys: value.get("trusted_keys")
+                .and_then(Json::as_object)
+                .map(|sources| sources.iter()
+                    .map(|(source, keys)| {
+                        let keys = keys.as_array()
+                            .map(|keys| keys.iter()
+                                .filter_map(|key| key.as_str().map(String::from))
+                                .collect()
+                            )
+                            .unwrap_or_default();
+
+                        (source.to_string(), keys)
+                    })
+                    .collect()
+                )
+                .unwrap_or(default.trusted_keys),
+
+            allow_unsigned: value.get("allow_unsigned")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.allow_unsigned),
+
+            hot_reload: value.get("hot_reload")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.hot_reload),
+
+            fixtures: value.get("fixtures")
+                .map(Fixtures::from)
+                .unwrap_or(default.fixtures),
+
+            log_levels: value.get("log_levels")
+                .and_then(Json::as_object)
+                .map(|levels| levels.iter()
+                    .map(|(game, level)| (game.to_string(), LogLevel::from(level)))
+                    .collect()
+                )
+                .unwrap_or(default.log_levels),
+
+            storage_limit: value.get("storage_limit")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.storage_limit)
+        }
+    }
+}
+
+/// Integration scripts execution limits for different calls categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Limits {
+    /// Card pictures, backgrounds and styles
+    pub visual: CallLimits,
+
+    /// Games and addons info, statuses and launch options
+    pub game: CallLimits,
+
+    /// Diff transitions hooks
+    pub transitions: CallLimits
+}
+
+impl Default for Limits {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            visual: CallLimits {
+                timeout: 15,
+                memory: 64
+            },
+
+            game: CallLimits {
+                timeout: 60,
+                memory: 256
+            },
+
+            transitions: CallLimits {
+                timeout: 1800,
+                memory: 1024
+            }
+        }
+    }
+}
+
+impl From<&Json> for Limits {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            visual: value.get("visual")
+                .map(|value| CallLimits::from_json(value, default.visual))
+                .unwrap_or(default.visual),
+
+            game: value.get("game")
+                .map(|value| CallLimits::from_json(value, default.game))
+                .unwrap_or(default.game),
+
+            transitions: value.get("transitions")
+                .map(|value| CallLimits::from_json(value, default.transitions))
+                .unwrap_or(default.transitions)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallLimits {
+    /// Max execution time, in seconds
+    pub timeout: u64,
+
+    /// Max lua memory usage, in megabytes
+    pub memory: u64
+}
+
+impl CallLimits {
+    #[inline]
+    fn from_json(value: &Json, default: Self) -> Self {
+        Self {
+            timeout: value.get("timeout")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.timeout),
+
+            memory: value.get("memory")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.memory)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FixturesMode {
+    /// Perform network requests as usual
+    Disabled,
+
+    /// Perform network requests and save them to the fixtures folder
+    Record,
+
+    /// Read network responses from the fixtures folder without network access
+    Replay
+}
+
+impl Default for FixturesMode {
+    #[inline]
+    fn default() -> Self {
+        Self::Disabled
+    }
+}
+
+impl From<&Json> for FixturesMode {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fixtures {
+    pub mode: FixturesMode,
+    pub path: PathBuf
+}
+
+impl Default for Fixtures {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            mode: FixturesMode::default(),
+            path: LAUNCHER_FOLDER.join("fixtures")
+        }
+    }
+}
+
+impl From<&Json> for Fixtures {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            mode: value.get("mode")
+                .map(FixturesMode::from)
+                .unwrap_or(default.mode),
+
             path: value.get("path")
                 .and_then(Json::as_str)
                 .map(PathBuf::from)
@@ -53,3 +268,29 @@ impl From<&Json> for Integrations {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+
+    /// Disable integration script logs
+    Off
+}
+
+impl Default for LogLevel {
+    #[inline]
+    fn default() -> Self {
+        Self::Trace
+    }
+}
+
+impl From<&Json> for LogLevel {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
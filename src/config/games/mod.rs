@@ -97,4 +97,33 @@ impl Games {
 
         Ok(settings)
     }
+
+    /// Get game editions paths stored in the config file
+    /// 
+    /// Unlike `get_game_settings` this method doesn't call the integration script,
+    /// so editions which weren't saved to the config file yet are not listed
+    pub fn get_game_edition_paths(&self, game_name: impl AsRef<str>) -> HashMap<String, GameEditionPaths> {
+        let Some(paths) = self.settings.get(game_name.as_ref()).and_then(|settings| settings.get("paths")).and_then(Json::as_object) else {
+            return HashMap::new();
+        };
+
+        paths.iter()
+            .filter_map(|(edition, paths)| {
+                GameEditionPaths::from_json(game_name.as_ref(), edition, paths).ok()
+                    .map(|paths| (edition.to_string(), paths))
+            })
+            .collect()
+    }
+
+    /// Get game edition's custom settings values stored in the config file
+    /// 
+    /// Same as `get_game_edition_paths`, this method doesn't call the integration script
+    pub fn get_game_custom_settings(&self, game_name: impl AsRef<str>, edition: impl AsRef<str>) -> HashMap<String, Json> {
+        self.settings.get(game_name.as_ref())
+            .and_then(|settings| settings.get("custom"))
+            .and_then(|custom| custom.get(edition.as_ref()))
+            .and_then(Json::as_object)
+            .map(|values| values.clone().into_iter().collect())
+            .unwrap_or_default()
+    }
 }
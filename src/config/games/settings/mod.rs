@@ -17,7 +17,10 @@ use prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GameSettings {
     pub paths: HashMap<String, GameEditionPaths>,
-    pub addons: HashMap<String, Vec<GameEditionAddon>>
+    pub addons: HashMap<String, Vec<GameEditionAddon>>,
+
+    /// Values of the settings defined by the integration script
+    pub custom: HashMap<String, HashMap<String, Json>>
 }
 
 impl GameSettings {
@@ -37,6 +40,8 @@ impl GameSettings {
                 .into_iter()
                 .map(|edition| (edition.as_ref().to_string(), vec![]))
                 .collect::<HashMap<_, _>>(),
+
+            custom: HashMap::new()
         })
     }
 
@@ -63,9 +68,22 @@ impl GameSettings {
             }
         }
 
+        if let Some(values) = value.get("custom").and_then(Json::as_object) {
+            for (edition, settings) in values.clone() {
+                if let Some(settings) = settings.as_object() {
+                    let settings = settings.clone()
+                        .into_iter()
+                        .collect();
+
+                    default.custom.insert(edition, settings);
+                }
+            }
+        }
+
         Ok(Self {
             paths: default.paths,
-            addons: default.addons
+            addons: default.addons,
+            custom: default.custom
         })
     }
 }
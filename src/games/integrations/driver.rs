@@ -1,160 +1,551 @@
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use cached::{Cached, UnboundCache};
+
 use serde_json::Value as Json;
 
 use mlua::prelude::*;
 
+use crate::config;
+
 use super::standards::prelude::*;
+use super::standards::scheduler::{self, RequestsNotifier};
+use super::standards::{filesystem, archive, integrity, error, log, settings, storage, ui, launcher};
+use super::standards::CallContext;
+use super::sandbox::{self, Capability};
+use super::limits::{self, CallCategory, LimitError};
+
+/// Additional time given to the driver's lua thread
+/// before the call is considered timed out
+const CALL_TIMEOUT_GRACE: Duration = Duration::from_secs(5);
+
+/// Request executed in the driver's lua thread
+type DriverRequest = Box<dyn FnOnce(&Lua, IntegrationStandard) + Send>;
+
+/// Check if the integration script has given function
+fn has_function(lua: &Lua, standard: IntegrationStandard, name: &str) -> LuaResult<bool> {
+    lua.globals().contains_key(standard.function_name(name))
+}
+
+/// Call integration script function
+/// 
+/// V2 functions are executed as coroutines so they can wait
+/// for network requests without blocking each other
+fn call_function<'lua, A, R>(lua: &'lua Lua, standard: IntegrationStandard, name: &str, args: A) -> LuaResult<R>
+where
+    A: IntoLuaMulti<'lua>,
+    R: FromLuaMulti<'lua>
+{
+    let function = lua.globals().get::<_, LuaFunction>(standard.function_name(name))?;
+
+    match standard {
+        IntegrationStandard::V1 => function.call(args),
+        IntegrationStandard::V2 => scheduler::run(lua, function, args)
+    }
+}
+
+/// Get table of the game edition's settings values
+/// 
+/// Returns empty table if the integration script doesn't define settings
+fn get_settings_values<'lua>(lua: &'lua Lua, standard: IntegrationStandard, game_name: &str, edition: &str) -> anyhow::Result<LuaTable<'lua>> {
+    if !has_function(lua, standard, "game_get_settings_schema")? {
+        return Ok(lua.create_table()?);
+    }
+
+    let schema = call_function::<_, LuaTable>(lua, standard, "game_get_settings_schema", edition)?
+        .sequence_values::<LuaTable>()
+        .flatten()
+        .map(|field| SettingsField::from_table(field, standard))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let values = config::get().games.get_game_custom_settings(game_name, edition);
+
+    settings::values_to_table(lua, &schema, &values, standard)
+}
+
+/// Counter of the created drivers used to identify them
+static NEXT_DRIVER_ID: AtomicU64 = AtomicU64::new(0);
 
 #[derive(Debug)]
 pub struct Driver {
     pub game_name: String,
     pub standard: IntegrationStandard,
-    pub lua: Lua
+
+    /// Unique driver identifier
+    ///
+    /// Memoized outputs are stored per driver, so the reloaded
+    /// integration doesn't get outputs of the previous script
+    id: u64,
+
+    capabilities: Vec<Capability>,
+    script: String,
+
+    /// Replaced when the lua thread is restarted after a timed out call
+    requests: Mutex<flume::Sender<DriverRequest>>
 }
 
 impl Driver {
-    pub fn new(game_name: impl ToString, standard: IntegrationStandard, script: impl AsRef<str>) -> anyhow::Result<Self> {
-        let lua = Lua::new();
+    /// Spawn new thread with the integration script loaded into its own lua engine
+    /// 
+    /// Lua engine is not thread safe, so it never leaves this thread,
+    /// and all the driver calls are sent to it through the channel
+    pub fn new(game_name: impl ToString, standard: IntegrationStandard, capabilities: &[Capability], script: impl ToString) -> anyhow::Result<Self> {
+        let game_name = game_name.to_string();
+        let capabilities = capabilities.to_vec();
+        let script = script.to_string();
 
-        match standard {
-            IntegrationStandard::V1 => {
-                lua.globals().set("v1_network_fetch", lua.create_function(|lua, (uri, options): (String, Option<RequestOptions>)| {
-                    let (method, headers, body, timeout) = match options {
-                        Some(options) => {
-                            let method = options.method.unwrap_or(RequestMethod::Get);
+        let requests = Self::spawn(&game_name, standard, &capabilities, &script)?;
 
-                            (method, options.headers, options.body, options.timeout)
-                        }
+        Ok(Self {
+            game_name,
+            standard,
+            id: NEXT_DRIVER_ID.fetch_add(1, Ordering::Relaxed),
+            capabilities,
+            script,
+            requests: Mutex::new(requests)
+        })
+    }
 
-                        None => (RequestMethod::Get, None, None, None)
-                    };
+    /// Spawn lua thread and wait until the script is loaded
+    fn spawn(game_name: &str, standard: IntegrationStandard, capabilities: &[Capability], script: &str) -> anyhow::Result<flume::Sender<DriverRequest>> {
+        let (requests, receiver) = flume::unbounded::<DriverRequest>();
+        let (init_sender, init_receiver) = flume::bounded(1);
 
-                    let mut request = anime_game_core::network::minreq::Request::new(method, uri);
+        let thread_game_name = game_name.to_string();
+        let capabilities = capabilities.to_vec();
+        let script = script.to_string();
 
-                    if let Some(headers) = headers {
-                        for (key, value) in headers {
-                            request = request.with_header(key, value);
-                        }
-                    }
+        std::thread::Builder::new()
+            .name(format!("driver:{game_name}"))
+            .spawn(move || {
+                let lua = match Self::create_lua(&thread_game_name, standard, &capabilities, &script) {
+                    Ok(lua) => lua,
 
-                    if let Some(body) = body {
-                        request = request.with_body(body);
-                    }
+                    Err(err) => {
+                        let _ = init_sender.send(Err(err));
 
-                    if let Some(timeout) = timeout {
-                        request = request.with_timeout(timeout);
+                        return;
                     }
+                };
+
+                let _ = init_sender.send(Ok(()));
 
-                    request.send()
-                        .map(|response| {
-                            let result = lua.create_table()?;
-                            let headers = lua.create_table()?;
-
-                            for (key, value) in &response.headers {
-                                headers.set(key.as_str(), value.as_str())?;
-                            }
-
-                            result.set("url", response.url.as_str())?;
-                            result.set("status", response.status_code)?;
-                            result.set("statusText", response.reason_phrase.as_str())?;
-                            result.set("ok", (200..300).contains(&response.status_code))?;
-                            result.set("headers", headers)?;
-                            result.set("body", lua.create_string(response.as_bytes())?)?;
-
-                            result.set("json", lua.create_function(move |lua, _: ()| {
-                                response.json::<Json>()
-                                    .map(|value| lua.to_value(&value))
-                                    .map_err(LuaError::external)
-                            })?)?;
-
-                            Ok::<LuaTable<'_>, mlua::Error>(result)
-                        })
+                // Process requests until the channel is dropped
+                while let Ok(request) = receiver.recv() {
+                    request(&lua, standard);
+                }
+            })?;
+
+        init_receiver.recv()??;
+
+        Ok(requests)
+    }
+
+    /// Replace lua thread with a new one
+    ///
+    /// Timed out script can still be running in the old thread
+    /// (e.g. blocked in a rust function where lua hooks can't stop it),
+    /// so new calls are sent to the new thread. The old one finishes
+    /// already queued requests and stops
+    fn restart(&self) {
+        tracing::warn!(game = %self.game_name, "Restarting integration driver after timed out call");
+
+        match Self::spawn(&self.game_name, self.standard, &self.capabilities, &self.script) {
+            Ok(requests) => {
+                if let Ok(mut current) = self.requests.lock() {
+                    *current = requests;
+                }
+            }
+
+            Err(err) => tracing::error!(game = %self.game_name, "Failed to restart integration driver: {err}")
+        }
+    }
+
+    fn create_lua(game_name: &str, standard: IntegrationStandard, capabilities: &[Capability], script: &str) -> anyhow::Result<Lua> {
+        let lua = sandbox::create_sandbox(game_name, capabilities)?;
+
+        filesystem::register(&lua, standard, game_name, capabilities)?;
+        archive::register(&lua, standard, game_name, capabilities)?;
+        integrity::register(&lua, standard, game_name, capabilities)?;
+        error::register(&lua, standard)?;
+        log::register(&lua, standard, game_name)?;
+        storage::register(&lua, standard, game_name)?;
+        ui::register(&lua, standard, game_name)?;
+        launcher::register(&lua, standard)?;
+
+        match standard {
+            IntegrationStandard::V1 => {
+                if !capabilities.contains(&Capability::Network) {
+                    lua.globals().set("v1_network_fetch", sandbox::refused_function(&lua, Capability::Network, "v1_network_fetch")?)?;
+                }
+
+                else {
+                    lua.globals().set("v1_network_fetch", lua.create_function(|lua, (uri, options): (String, Option<RequestOptions>)| {
+                        let response = fetch(uri, options)
+                            .map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+                        response_to_table(lua, response)
+                    })?)?;
+                }
+
+                lua.globals().set("v1_json_decode", lua.create_function(|lua, json: String| {
+                    serde_json::from_str::<Json>(&json)
+                        .map(|value| lua.to_value(&value))
                         .map_err(LuaError::external)
                 })?)?;
-        
-                lua.globals().set("v1_json_decode", lua.create_function(|lua, json: String| {
+            }
+
+            IntegrationStandard::V2 => {
+                let (notifier, receiver) = flume::unbounded();
+
+                lua.set_app_data(RequestsNotifier(receiver));
+
+                if !capabilities.contains(&Capability::Network) {
+                    lua.globals().set("v2_network_fetch_async", sandbox::refused_function(&lua, Capability::Network, "v2_network_fetch_async")?)?;
+                }
+
+                else {
+                    lua.globals().set("v2_network_fetch_async", lua.create_function(move |_, (uri, options): (String, Option<RequestOptions>)| {
+                        Ok(PendingRequest::start(uri, options, notifier.clone()))
+                    })?)?;
+                }
+
+                lua.globals().set("v2_json_decode", lua.create_function(|lua, json: String| {
                     serde_json::from_str::<Json>(&json)
                         .map(|value| lua.to_value(&value))
                         .map_err(LuaError::external)
                 })?)?;
+
+                lua.globals().set("v2_parallel", lua.create_function(|lua, functions: LuaVariadic<LuaFunction>| {
+                    let threads = functions.iter()
+                        .map(|function| Ok((lua.create_thread(function.clone())?, LuaMultiValue::new())))
+                        .collect::<LuaResult<Vec<_>>>()?;
+
+                    let results = lua.create_table()?;
+
+                    for (i, result) in scheduler::run_threads(lua, threads)?.into_iter().enumerate() {
+                        results.raw_set(i + 1, result.into_iter().next().unwrap_or(LuaNil))?;
+                    }
+
+                    Ok(results)
+                })?)?;
+
+                lua.load(scheduler::V2_PRELUDE).exec()?;
             }
         }
 
-        lua.load(script.as_ref()).exec()?;
+        let violation = limits::set_limits(&lua, game_name, CallCategory::Game.limits(), Arc::default());
 
-        Ok(Self {
-            game_name: game_name.to_string(),
-            standard,
-            lua
+        let result = lua.load(script).exec();
+
+        limits::remove_limits(&lua);
+
+        if let Some(err) = violation.take() {
+            anyhow::bail!(err);
+        }
+
+        if let Err(err) = result {
+            return Err(error::convert_error(err.into()));
+        }
+
+        Ok(lua)
+    }
+
+    /// Execute given callback in the driver's lua thread and wait for its result
+    /// 
+    /// Execution is stopped with `LimitError` when it exceeds
+    /// time or memory limits of the given calls category
+    #[inline]
+    pub fn call<T: Send + 'static>(&self, category: CallCategory, callback: impl FnOnce(&Lua, IntegrationStandard) -> anyhow::Result<T> + Send + 'static) -> anyhow::Result<T> {
+        self.call_with_context(category, None, callback)
+    }
+
+    /// Same as `call`, but the integration script knows the game edition
+    /// it's called for, e.g. to tag its logs or to access its storage
+    #[inline]
+    pub fn call_for_edition<T: Send + 'static>(&self, category: CallCategory, edition: impl ToString, callback: impl FnOnce(&Lua, IntegrationStandard) -> anyhow::Result<T> + Send + 'static) -> anyhow::Result<T> {
+        self.call_with_context(category, Some(edition.to_string()), callback)
+    }
+
+    fn call_with_context<T: Send + 'static>(&self, category: CallCategory, edition: Option<String>, callback: impl FnOnce(&Lua, IntegrationStandard) -> anyhow::Result<T> + Send + 'static) -> anyhow::Result<T> {
+        let call_limits = category.limits();
+        let game_name = self.game_name.clone();
+
+        // GTK main thread can't process dialogs while it's waiting for the response
+        let context = CallContext {
+            edition,
+            interactive: !gtk::glib::MainContext::default().is_owner()
+        };
+
+        let paused = Arc::new(AtomicBool::new(false));
+        let call_paused = paused.clone();
+
+        let (sender, receiver) = flume::bounded(1);
+
+        let request = Box::new(move |lua: &Lua, standard: IntegrationStandard| {
+            let violation = limits::set_limits(lua, &game_name, call_limits, call_paused);
+
+            lua.set_app_data(context);
+
+            let result = callback(lua, standard)
+                .map_err(error::convert_error);
+
+            lua.remove_app_data::<CallContext>();
+
+            limits::remove_limits(lua);
+
+            // Replace lua error with more specific one
+            let result = match violation.take() {
+                Some(err) => Err(anyhow::Error::new(err)),
+
+                None => match result {
+                    Err(err) if limits::is_memory_error(&err) => Err(anyhow::Error::new(LimitError::Memory {
+                        game_name: game_name.clone(),
+                        megabytes: call_limits.memory
+                    })),
+
+                    result => result
+                }
+            };
+
+            let _ = sender.send(result);
+        });
+
+        let sent = self.requests.lock()
+            .map(|requests| requests.send(request).is_ok())
+            .unwrap_or(false);
+
+        if !sent {
+            anyhow::bail!("{} integration driver is not running", self.game_name);
+        }
+
+        // Lua hooks can't interrupt blocking rust functions (e.g. network requests)
+        // so we additionally stop waiting for the response after the timeout
+        let timeout = Duration::from_secs(call_limits.timeout) + CALL_TIMEOUT_GRACE;
+
+        loop {
+            match receiver.recv_timeout(timeout) {
+                Ok(result) => return result,
+
+                // Time spent waiting for the user is not limited
+                Err(flume::RecvTimeoutError::Timeout) if paused.load(Ordering::Relaxed) => continue,
+
+                Err(flume::RecvTimeoutError::Timeout) => {
+                    // Script is still running and would block all the next calls
+                    self.restart();
+
+                    return Err(anyhow::Error::new(LimitError::Timeout {
+                        game_name: self.game_name.clone(),
+                        seconds: call_limits.timeout
+                    }));
+                }
+
+                Err(flume::RecvTimeoutError::Disconnected) => anyhow::bail!("{} integration driver has stopped while processing the request", self.game_name)
+            }
+        }
+    }
+
+    /// Check if the integration script has given function
+    /// 
+    /// Name is specified without the standard prefix
+    pub fn has_function(&self, name: &str) -> anyhow::Result<bool> {
+        let name = name.to_string();
+
+        self.call(CallCategory::Game, move |lua, standard| {
+            Ok(has_function(lua, standard, &name)?)
         })
     }
+
+    /// Remove memoized outputs of the integration script
+    pub fn flush_cache(&self) {
+        remove_driver_entries(&CARD_PICTURE_CACHE, self.id, |key| key.0);
+        remove_driver_entries(&BACKGROUND_PICTURE_CACHE, self.id, |key| key.0);
+        remove_driver_entries(&DETAILS_BACKGROUND_STYLE_CACHE, self.id, |key| key.0);
+        remove_driver_entries(&GAME_EDITIONS_LIST_CACHE, self.id, |id| *id);
+        remove_driver_entries(&ADDONS_LIST_CACHE, self.id, |key| key.0);
+        remove_driver_entries(&NEWS_CACHE, self.id, |key| key.0);
+    }
+}
+
+#[inline]
+#[cached::proc_macro::cached(
+    name = "CARD_PICTURE_CACHE",
+    key = "(u64, String)",
+    convert = r##"{ (driver.id, edition.to_string()) }"##,
+    result
+)]
+fn cached_get_card_picture(driver: &Driver, edition: &str) -> anyhow::Result<String> {
+    let edition = edition.to_string();
+
+    driver.call_for_edition(CallCategory::Visual, edition.clone(), move |lua, standard| {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "visual_get_card_picture", edition)?)
+        }
+    })
+}
+
+#[inline]
+#[cached::proc_macro::cached(
+    name = "BACKGROUND_PICTURE_CACHE",
+    key = "(u64, String)",
+    convert = r##"{ (driver.id, edition.to_string()) }"##,
+    result
+)]
+fn cached_get_background_picture(driver: &Driver, edition: &str) -> anyhow::Result<String> {
+    let edition = edition.to_string();
+
+    driver.call_for_edition(CallCategory::Visual, edition.clone(), move |lua, standard| {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "visual_get_background_picture", edition)?)
+        }
+    })
 }
 
-// Let (at least for now) lua scripts maintainers resolve
-// possible data races themselves
-// 
-// FIXME: use Mutex or RwLock, or anything else but please remove this shit
+#[inline]
+#[cached::proc_macro::cached(
+    name = "DETAILS_BACKGROUND_STYLE_CACHE",
+    key = "(u64, String)",
+    convert = r##"{ (driver.id, edition.to_string()) }"##,
+    result
+)]
+fn cached_get_details_background_style(driver: &Driver, edition: &str) -> anyhow::Result<Option<String>> {
+    let edition = edition.to_string();
+
+    driver.call_for_edition(CallCategory::Visual, edition.clone(), move |lua, standard| {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                if !has_function(lua, standard, "visual_get_details_background_css")? {
+                    return Ok(None);
+                }
 
-unsafe impl Send for Driver {}
-unsafe impl Sync for Driver {}
+                Ok(call_function(lua, standard, "visual_get_details_background_css", edition)?)
+            }
+        }
+    })
+}
 
-impl Driver {
-    #[inline]
-    pub fn get_card_picture(&self, edition: &str) -> anyhow::Result<String> {
-        #[inline]
-        #[cached::proc_macro::cached(
-            key = "String",
-            convert = r##"{ format!("{_game}{edition}") }"##,
-            result
-        )]
-        fn get_card_picture(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<String> {
-            match standard {
-                IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_visual_get_card_picture", edition)?)
+#[inline]
+#[cached::proc_macro::cached(
+    name = "GAME_EDITIONS_LIST_CACHE",
+    key = "u64",
+    convert = r##"{ driver.id }"##,
+    result
+)]
+fn cached_get_game_editions_list(driver: &Driver) -> anyhow::Result<Vec<GameEdition>> {
+    driver.call(CallCategory::Game, move |lua, standard| {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                let editions = call_function::<_, LuaTable>(lua, standard, "game_get_editions_list", ())?
+                    .sequence_values::<LuaTable>()
+                    .flatten()
+                    .map(|edition| GameEdition::from_table(edition, standard))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(editions)
+            }
+        }
+    })
+}
+
+#[inline]
+#[cached::proc_macro::cached(
+    name = "ADDONS_LIST_CACHE",
+    key = "(u64, String)",
+    convert = r##"{ (driver.id, edition.to_string()) }"##,
+    result
+)]
+fn cached_get_addons_list(driver: &Driver, edition: &str) -> anyhow::Result<Vec<AddonsGroup>> {
+    let edition = edition.to_string();
+
+    driver.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                let addons = call_function::<_, LuaTable>(lua, standard, "addons_get_list", edition)?
+                    .sequence_values::<LuaTable>()
+                    .flatten()
+                    .map(|group| AddonsGroup::from_table(group, standard))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(addons)
+            }
+        }
+    })
+}
+
+#[inline]
+#[cached::proc_macro::cached(
+    name = "NEWS_CACHE",
+    key = "(u64, String, String)",
+    convert = r##"{ (driver.id, edition.to_string(), language.to_string()) }"##,
+    result
+)]
+fn cached_get_news(driver: &Driver, edition: &str, language: &str) -> anyhow::Result<Vec<NewsItem>> {
+    let edition = edition.to_string();
+    let language = language.to_string();
+
+    driver.call_for_edition(CallCategory::Visual, edition.clone(), move |lua, standard| {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                if !has_function(lua, standard, "game_get_news")? {
+                    return Ok(vec![]);
+                }
+
+                let news = call_function::<_, LuaTable>(lua, standard, "game_get_news", (edition, language))?
+                    .sequence_values::<LuaTable>()
+                    .flatten()
+                    .map(|item| NewsItem::from_table(item, standard))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(news)
             }
         }
+    })
+}
+
+/// Remove memoized outputs of the driver from the cache
+fn remove_driver_entries<K, V>(cache: &Mutex<UnboundCache<K, V>>, id: u64, get_id: impl Fn(&K) -> u64)
+where
+    K: std::hash::Hash + Eq + Clone
+{
+    let Ok(mut cache) = cache.lock() else {
+        return;
+    };
+
+    let keys = cache.get_store()
+        .keys()
+        .filter(|key| get_id(key) == id)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for key in keys {
+        cache.cache_remove(&key);
+    }
+}
 
-        get_card_picture(&self.lua, self.standard, &self.game_name, edition)
+impl Driver {
+    #[inline]
+    pub fn get_card_picture(&self, edition: &str) -> anyhow::Result<String> {
+        cached_get_card_picture(self, edition)
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_background_picture(&self, edition: &str) -> anyhow::Result<String> {
-        #[inline]
-        #[cached::proc_macro::cached(
-            key = "String",
-            convert = r##"{ format!("{_game}{edition}") }"##,
-            result
-        )]
-        fn get_background_picture(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<String> {
-            match standard {
-                IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_visual_get_background_picture", edition)?)
-            }
-        }
-
-        get_background_picture(&self.lua, self.standard, &self.game_name, edition)
+        cached_get_background_picture(self, edition)
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_details_background_style(&self, edition: &str) -> anyhow::Result<Option<String>> {
-        #[inline]
-        #[cached::proc_macro::cached(
-            key = "String",
-            convert = r##"{ format!("{_game}{edition}") }"##,
-            result
-        )]
-        fn get_details_background_style(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<Option<String>> {
-            match standard {
-                IntegrationStandard::V1 => {
-                    if !lua.globals().contains_key("v1_visual_get_details_background_css")? {
-                        return Ok(None);
-                    }
-
-                    Ok(lua.globals().call_function("v1_visual_get_details_background_css", edition)?)
-                }
-            }
-        }
+        cached_get_details_background_style(self, edition)
+    }
 
-        get_details_background_style(&self.lua, self.standard, &self.game_name, edition)
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn get_news(&self, edition: &str, language: &str) -> anyhow::Result<Vec<NewsItem>> {
+        cached_get_news(self, edition, language)
     }
 
     // Game
@@ -162,139 +553,222 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_editions_list(&self) -> anyhow::Result<Vec<GameEdition>> {
-        #[inline]
-        #[cached::proc_macro::cached(
-            key = "String",
-            convert = r##"{ _game.to_string() }"##,
-            result
-        )]
-        fn get_game_editions_list(lua: &Lua, standard: IntegrationStandard, _game: &str) -> anyhow::Result<Vec<GameEdition>> {
-            match standard {
-                IntegrationStandard::V1 => {
-                    let editions = lua.globals()
-                        .call_function::<_, LuaTable>("v1_game_get_editions_list", ())?
-                        .sequence_values::<LuaTable>()
-                        .flatten()
-                        .map(|edition| GameEdition::from_table(edition, standard))
-                        .collect::<Result<Vec<_>, _>>()?;
-
-                    Ok(editions)
-                }
-            }
-        }
-
-        get_game_editions_list(&self.lua, self.standard, &self.game_name)
+        cached_get_game_editions_list(self)
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn is_game_installed(&self, path: &str, edition: &str) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_is_installed", (path, edition))?)
-        }
+        let path = path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_is_installed", (path, edition))?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_version(&self, path: &str, edition: &str) -> anyhow::Result<Option<String>> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_get_version", (path, edition))?)
-        }
+        let path = path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_get_version", (path, edition))?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_download(&self, edition: &str) -> anyhow::Result<Download> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let download = self.lua.globals()
-                    .call_function("v1_game_get_download", edition)?;
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let download = call_function(lua, standard, "game_get_download", edition)?;
 
-                Download::from_table(download, self.standard)
+                    Download::from_table(download, standard)
+                }
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_diff(&self, path: &str, edition: &str) -> anyhow::Result<Option<Diff>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let diff = self.lua.globals()
-                    .call_function("v1_game_get_diff", (path, edition))?;
+        let game_name = self.game_name.clone();
+        let path = path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let settings = get_settings_values(lua, standard, &game_name, &edition)?;
 
-                match diff {
-                    Some(diff) => Ok(Some(Diff::from_table(diff, self.standard)?)),
-                    None => Ok(None)
+                    let diff = call_function(lua, standard, "game_get_diff", (path, edition, settings))?;
+
+                    match diff {
+                        Some(diff) => Ok(Some(Diff::from_table(diff, standard)?)),
+                        None => Ok(None)
+                    }
                 }
             }
-        }
+        })
+    }
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn get_settings_schema(&self, edition: &str) -> anyhow::Result<Vec<SettingsField>> {
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    if !has_function(lua, standard, "game_get_settings_schema")? {
+                        return Ok(vec![]);
+                    }
+
+                    call_function::<_, LuaTable>(lua, standard, "game_get_settings_schema", edition)?
+                        .sequence_values::<LuaTable>()
+                        .flatten()
+                        .map(|field| SettingsField::from_table(field, standard))
+                        .collect()
+                }
+            }
+        })
+    }
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn get_prefix_requirements(&self, edition: &str) -> anyhow::Result<PrefixRequirements> {
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    if !has_function(lua, standard, "game_get_prefix_requirements")? {
+                        return Ok(PrefixRequirements::default());
+                    }
+
+                    let requirements = call_function::<_, LuaTable>(lua, standard, "game_get_prefix_requirements", edition)?;
+
+                    PrefixRequirements::from_table(requirements, standard)
+                }
+            }
+        })
+    }
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn get_compatibility(&self, edition: &str) -> anyhow::Result<Compatibility> {
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    if !has_function(lua, standard, "game_get_compatibility")? {
+                        return Ok(Compatibility::default());
+                    }
+
+                    let compatibility = call_function::<_, LuaTable>(lua, standard, "game_get_compatibility", edition)?;
+
+                    Compatibility::from_table(compatibility, standard)
+                }
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_status(&self, path: &str, edition: &str) -> anyhow::Result<Option<GameStatus>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let status = self.lua.globals()
-                    .call_function("v1_game_get_status", (path, edition))?;
+        let path = path.to_string();
+        let edition = edition.to_string();
 
-                match status {
-                    Some(status) => Ok(Some(GameStatus::from_table(status, self.standard)?)),
-                    None => Ok(None)
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let status = call_function(lua, standard, "game_get_status", (path, edition))?;
+
+                    match status {
+                        Some(status) => Ok(Some(GameStatus::from_table(status, standard)?)),
+                        None => Ok(None)
+                    }
                 }
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_launch_options(&self, game_path: &str, addons_path: &str, edition: &str) -> anyhow::Result<GameLaunchOptions> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let options = self.lua.globals()
-                    .call_function("v1_game_get_launch_options", (game_path, addons_path, edition))?;
+        let game_name = self.game_name.clone();
+        let game_path = game_path.to_string();
+        let addons_path = addons_path.to_string();
+        let edition = edition.to_string();
 
-                GameLaunchOptions::from_table(options, self.standard)
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let settings = get_settings_values(lua, standard, &game_name, &edition)?;
+
+                    let options = call_function(lua, standard, "game_get_launch_options", (game_path, addons_path, edition, settings))?;
+
+                    GameLaunchOptions::from_table(options, standard)
+                }
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn is_process_running(&self, game_path: &str, edition: &str) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_is_running", (game_path, edition))?)
-        }
+        let game_path = game_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_is_running", (game_path, edition))?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn kill_process(&self, game_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_kill", (game_path, edition))?)
-        }
+        let game_path = game_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_kill", (game_path, edition))?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace")]
     pub fn get_game_integrity(&self, game_path: &str, edition: &str) -> anyhow::Result<Vec<IntegrityInfo>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let info = self.lua.globals()
-                    .call_function::<_, LuaTable>("v1_game_get_integrity_info", (game_path, edition))?
-                    .sequence_values::<LuaTable>()
-                    .flatten()
-                    .map(|info| IntegrityInfo::from_table(info, self.standard))
-                    .collect::<Result<Vec<_>, _>>()?;
+        let game_path = game_path.to_string();
+        let edition = edition.to_string();
 
-                Ok(info)
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let info = call_function::<_, LuaTable>(lua, standard, "game_get_integrity_info", (game_path, edition))?
+                        .sequence_values::<LuaTable>()
+                        .flatten()
+                        .map(|info| IntegrityInfo::from_table(info, standard))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    Ok(info)
+                }
             }
-        }
+        })
     }
 
     // Addons
@@ -302,130 +776,144 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addons_list(&self, edition: &str) -> anyhow::Result<Vec<AddonsGroup>> {
-        #[inline]
-        #[cached::proc_macro::cached(
-            key = "String",
-            convert = r##"{ format!("{_game}{edition}") }"##,
-            result
-        )]
-        fn get_addons_list(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<Vec<AddonsGroup>> {
-            match standard {
-                IntegrationStandard::V1 => {
-                    let addons = lua.globals()
-                        .call_function::<_, LuaTable>("v1_addons_get_list", edition)?
-                        .sequence_values::<LuaTable>()
-                        .flatten()
-                        .map(|group| AddonsGroup::from_table(group, standard))
-                        .collect::<Result<Vec<_>, _>>()?;
-
-                    Ok(addons)
-                }
-            }
-        }
-
-        get_addons_list(&self.lua, self.standard, &self.game_name, edition)
+        cached_get_addons_list(self, edition)
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn is_addon_installed(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_is_installed", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let addon_path = addon_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "addons_is_installed", (
                     group_name,
                     addon_name,
                     addon_path,
                     edition
                 ))?)
-        }
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_version(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Option<String>> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_get_version", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let addon_path = addon_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "addons_get_version", (
                     group_name,
                     addon_name,
                     addon_path,
                     edition
                 ))?)
-        }
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_download(&self, group_name: &str, addon_name: &str, edition: &str) -> anyhow::Result<Download> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let download = self.lua.globals()
-                    .call_function("v1_addons_get_download", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let download = call_function(lua, standard, "addons_get_download", (
                         group_name,
                         addon_name,
                         edition
                     ))?;
 
-                Download::from_table(download, self.standard)
+                    Download::from_table(download, standard)
+                }
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_diff(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Option<Diff>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let diff = self.lua.globals()
-                    .call_function("v1_addons_get_diff", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let addon_path = addon_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let diff = call_function(lua, standard, "addons_get_diff", (
                         group_name,
                         addon_name,
                         addon_path,
                         edition
                     ))?;
 
-                match diff {
-                    Some(diff) => Ok(Some(Diff::from_table(diff, self.standard)?)),
-                    None => Ok(None)
+                    match diff {
+                        Some(diff) => Ok(Some(Diff::from_table(diff, standard)?)),
+                        None => Ok(None)
+                    }
                 }
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_paths(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Vec<String>> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_get_paths", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let addon_path = addon_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "addons_get_paths", (
                     group_name,
                     addon_name,
                     addon_path,
                     edition
                 ))?)
-        }
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace")]
     pub fn get_addon_integrity(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Vec<IntegrityInfo>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let info = self.lua.globals()
-                    .call_function::<_, LuaTable>("v1_addons_get_integrity_info", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let addon_path = addon_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Game, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                    let info = call_function::<_, LuaTable>(lua, standard, "addons_get_integrity_info", (
                         group_name,
                         addon_name,
                         addon_path,
                         edition
                     ))?
-                    .sequence_values::<LuaTable>()
-                    .flatten()
-                    .map(|info| IntegrityInfo::from_table(info, self.standard))
-                    .collect::<Result<Vec<_>, _>>()?;
+                        .sequence_values::<LuaTable>()
+                        .flatten()
+                        .map(|info| IntegrityInfo::from_table(info, standard))
+                        .collect::<Result<Vec<_>, _>>()?;
 
-                Ok(info)
+                    Ok(info)
+                }
             }
-        }
+        })
     }
 
     // Game transitions
@@ -433,52 +921,70 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_game_diff_pre_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_game_diff_pre_transition")?)
-        }
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "game_diff_pre_transition")?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_game_diff_pre_transition(&self, path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_diff_pre_transition", (path, edition))?)
-        }
+        let path = path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_diff_pre_transition", (path, edition))?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_game_diff_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_game_diff_transition")?)
-        }
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "game_diff_transition")?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_game_diff_transition(&self, transition_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_diff_transition", (transition_path, edition))?)
-        }
+        let transition_path = transition_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_diff_transition", (transition_path, edition))?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_game_diff_post_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_game_diff_post_transition")?)
-        }
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "game_diff_post_transition")?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_game_diff_post_transition(&self, path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_diff_post_transition", (path, edition))?)
-        }
+        let path = path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_diff_post_transition", (path, edition))?)
+            }
+        })
     }
 
     // Addons transitions
@@ -486,67 +992,141 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_addons_diff_pre_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_addons_diff_pre_transition")?)
-        }
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "addons_diff_pre_transition")?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_addons_diff_pre_transition(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_diff_pre_transition", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let addon_path = addon_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "addons_diff_pre_transition", (
                     group_name,
                     addon_name,
                     addon_path,
                     edition
                 ))?)
-        }
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_addons_diff_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_addons_diff_transition")?)
-        }
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "addons_diff_transition")?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_addons_diff_transition(&self, group_name: &str, addon_name: &str, transition_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_diff_transition", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let transition_path = transition_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "addons_diff_transition", (
                     group_name,
                     addon_name,
                     transition_path,
                     edition
                 ))?)
-        }
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_addons_diff_post_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_addons_diff_post_transition")?)
-        }
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "addons_diff_post_transition")?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_addons_diff_post_transition(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_diff_post_transition", (
+        let group_name = group_name.to_string();
+        let addon_name = addon_name.to_string();
+        let addon_path = addon_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "addons_diff_post_transition", (
                     group_name,
                     addon_name,
                     addon_path,
                     edition
                 ))?)
-        }
+            }
+        })
+    }
+
+    // Launch hooks
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn has_game_pre_launch(&self) -> anyhow::Result<bool> {
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "game_pre_launch")?)
+            }
+        })
+    }
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn run_game_pre_launch(&self, game_path: &str, addons_path: &str, edition: &str) -> anyhow::Result<()> {
+        let game_path = game_path.to_string();
+        let addons_path = addons_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_pre_launch", (game_path, addons_path, edition))?)
+            }
+        })
+    }
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn has_game_post_exit(&self) -> anyhow::Result<bool> {
+        self.call(CallCategory::Transitions, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "game_post_exit")?)
+            }
+        })
+    }
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn run_game_post_exit(&self, game_path: &str, addons_path: &str, edition: &str) -> anyhow::Result<()> {
+        let game_path = game_path.to_string();
+        let addons_path = addons_path.to_string();
+        let edition = edition.to_string();
+
+        self.call_for_edition(CallCategory::Transitions, edition.clone(), move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "game_post_exit", (game_path, addons_path, edition))?)
+            }
+        })
     }
 
     // Hashes
@@ -554,20 +1134,26 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_integrity_hash(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_integrity_hash")?)
-        }
+        self.call(CallCategory::Game, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(has_function(lua, standard, "integrity_hash")?)
+            }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", skip(data), ret)]
     pub fn integrity_hash(&self, algorithm: &str, data: impl AsRef<[u8]>) -> anyhow::Result<String> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_integrity_hash", (
+        let algorithm = algorithm.to_string();
+        let data = data.as_ref().to_vec();
+
+        self.call(CallCategory::Game, move |lua, standard| {
+            match standard {
+                IntegrationStandard::V1 | IntegrationStandard::V2 => Ok(call_function(lua, standard, "integrity_hash", (
                     algorithm,
-                    self.lua.create_string(data)?
+                    lua.create_string(data)?
                 ))?)
-        }
+            }
+        })
     }
 }
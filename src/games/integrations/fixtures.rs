@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Serialize, Deserialize};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use crate::config;
+use crate::config::games::integrations::{Fixtures, FixturesMode};
+
+use super::standards::network::{RequestOptions, RequestMethod, Response};
+
+/// Environment variable which overrides fixtures mode from the config
+///
+/// Accepts `disabled`, `record` and `replay` values
+pub const FIXTURES_MODE_VARIABLE: &str = "LAUNCHER_NETWORK_FIXTURES";
+
+/// Environment variable which overrides fixtures folder from the config
+pub const FIXTURES_PATH_VARIABLE: &str = "LAUNCHER_NETWORK_FIXTURES_PATH";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct FixtureRequest {
+    url: String,
+    method: String,
+    headers: HashMap<String, String>,
+
+    /// Base64 encoded request body
+    body: String
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct FixtureResponse {
+    url: String,
+    status: i32,
+    status_text: String,
+    headers: HashMap<String, String>,
+
+    /// Base64 encoded response body
+    body: String
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Fixture {
+    request: FixtureRequest,
+    response: FixtureResponse
+}
+
+/// Get fixtures settings
+///
+/// Environment variables have higher priority than the config
+pub fn get_settings() -> Fixtures {
+    let mut fixtures = config::get().games.integrations.fixtures;
+
+    if let Ok(mode) = std::env::var(FIXTURES_MODE_VARIABLE) {
+        match mode.to_ascii_lowercase().as_str() {
+            "disabled" => fixtures.mode = FixturesMode::Disabled,
+            "record"   => fixtures.mode = FixturesMode::Record,
+            "replay"   => fixtures.mode = FixturesMode::Replay,
+
+            _ => tracing::warn!("Unknown {FIXTURES_MODE_VARIABLE} value: {mode}")
+        }
+    }
+
+    if let Ok(path) = std::env::var(FIXTURES_PATH_VARIABLE) {
+        fixtures.path = PathBuf::from(path);
+    }
+
+    fixtures
+}
+
+/// Get path to the request's fixture file
+///
+/// Requests are identified by their method, URI and body.
+/// Headers are not used because they can contain tokens or timestamps
+fn get_fixture_path(folder: &Path, uri: &str, options: Option<&RequestOptions>) -> PathBuf {
+    let method = options.and_then(|options| options.method.clone())
+        .unwrap_or(RequestMethod::Get);
+
+    let body = options.and_then(|options| options.body.as_deref())
+        .unwrap_or_default();
+
+    let mut key = format!("{method} {uri}\n").into_bytes();
+
+    key.extend_from_slice(body);
+
+    let hash = xxhash_rust::xxh3::xxh3_128(&key);
+
+    folder.join(format!("{hash:032x}.json"))
+}
+
+/// Save network request and its response to the fixtures folder
+pub fn record(folder: &Path, uri: &str, options: Option<&RequestOptions>, response: &Response) -> anyhow::Result<()> {
+    let path = get_fixture_path(folder, uri, options);
+
+    let fixture = Fixture {
+        request: FixtureRequest {
+            url: uri.to_string(),
+
+            method: options.and_then(|options| options.method.clone())
+                .unwrap_or(RequestMethod::Get)
+                .to_string(),
+
+            headers: options.and_then(|options| options.headers.clone())
+                .unwrap_or_default(),
+
+            body: BASE64.encode(options.and_then(|options| options.body.as_deref()).unwrap_or_default())
+        },
+
+        response: FixtureResponse {
+            url: response.url.clone(),
+            status: response.status,
+            status_text: response.status_text.clone(),
+            headers: response.headers.clone(),
+            body: BASE64.encode(&response.body)
+        }
+    };
+
+    tracing::trace!("Recording {uri} network fixture to {path:?}");
+
+    std::fs::create_dir_all(folder)?;
+    std::fs::write(path, serde_json::to_string_pretty(&fixture)?)?;
+
+    Ok(())
+}
+
+/// Read network response from the fixtures folder
+pub fn replay(folder: &Path, uri: &str, options: Option<&RequestOptions>) -> anyhow::Result<Response> {
+    let path = get_fixture_path(folder, uri, options);
+
+    if !path.exists() {
+        anyhow::bail!("Network fixture for {uri} is not recorded (expected {path:?})");
+    }
+
+    tracing::trace!("Replaying {uri} network fixture from {path:?}");
+
+    let fixture = serde_json::from_slice::<Fixture>(&std::fs::read(path)?)?;
+
+    Ok(Response {
+        url: fixture.response.url,
+        status: fixture.response.status,
+        status_text: fixture.response.status_text,
+        headers: fixture.response.headers,
+        body: BASE64.decode(fixture.response.body)?
+    })
+}
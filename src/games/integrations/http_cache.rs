@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Serialize, Deserialize};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use super::standards::network::Response;
+
+use crate::NETWORK_CACHE_FOLDER;
+
+/// Request headers which make the response specific to the user
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "cookie"];
+
+/// Response stored in the network cache
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Request URL with its headers, see `get_key`
+    #[serde(default)]
+    pub key: String,
+
+    pub url: String,
+    pub status: i32,
+    pub status_text: String,
+    pub headers: HashMap<String, String>,
+
+    /// Base64 encoded response body
+    pub body: String,
+
+    /// UNIX timestamp of the last response validation
+    pub validated_at: u64,
+
+    /// Time in seconds the response is fresh for after validation
+    pub max_age: u64
+}
+
+impl CacheEntry {
+    /// Check if the response can be used without validation
+    #[inline]
+    pub fn is_fresh(&self) -> bool {
+        now() < self.validated_at + self.max_age
+    }
+
+    #[inline]
+    pub fn etag(&self) -> Option<&str> {
+        self.headers.get("etag").map(String::as_str)
+    }
+
+    #[inline]
+    pub fn last_modified(&self) -> Option<&str> {
+        self.headers.get("last-modified").map(String::as_str)
+    }
+
+    pub fn to_response(&self) -> anyhow::Result<Response> {
+        Ok(Response {
+            url: self.url.clone(),
+            status: self.status,
+            status_text: self.status_text.clone(),
+            headers: self.headers.clone(),
+            body: BASE64.decode(&self.body)?
+        })
+    }
+}
+
+/// Parsed `Cache-Control` response header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CacheControl {
+    no_store: bool,
+    private: bool,
+    max_age: u64
+}
+
+impl CacheControl {
+    fn from_headers(headers: &HashMap<String, String>) -> Self {
+        let mut control = Self {
+            no_store: false,
+            private: false,
+
+            // Responses without max age are always validated
+            max_age: 0
+        };
+
+        let Some(header) = headers.get("cache-control") else {
+            return control;
+        };
+
+        for directive in header.split(',') {
+            let directive = directive.trim().to_ascii_lowercase();
+
+            if directive == "no-store" {
+                control.no_store = true;
+            }
+
+            else if directive == "private" || directive.starts_with("private=") {
+                control.private = true;
+            }
+
+            else if directive == "no-cache" {
+                control.max_age = 0;
+
+                // no-cache has priority over max-age
+                break;
+            }
+
+            else if let Some(max_age) = directive.strip_prefix("max-age=") {
+                control.max_age = max_age.trim_matches('"').parse().unwrap_or(0);
+            }
+        }
+
+        control
+    }
+}
+
+#[inline]
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+#[inline]
+fn get_entry_path(key: &str) -> PathBuf {
+    let hash = xxhash_rust::xxh3::xxh3_128(key.as_bytes());
+
+    NETWORK_CACHE_FOLDER.join(format!("{hash:032x}.json"))
+}
+
+/// Get cache key of the request
+///
+/// All the request headers are included in the key, so responses
+/// are never shared between requests which could vary by them
+pub fn get_key(url: &str, headers: Option<&HashMap<String, String>>) -> String {
+    let mut headers = headers.into_iter()
+        .flatten()
+        .map(|(name, value)| format!("{}: {value}", name.to_ascii_lowercase()))
+        .collect::<Vec<_>>();
+
+    headers.sort();
+
+    std::iter::once(url.to_string())
+        .chain(headers)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Check if the request contains user credentials,
+/// so its response must not be cached
+pub fn is_private_request(headers: Option<&HashMap<String, String>>) -> bool {
+    headers.into_iter()
+        .flatten()
+        .any(|(name, _)| CREDENTIAL_HEADERS.contains(&name.to_ascii_lowercase().as_str()))
+}
+
+/// Check if the response can be stored in the cache
+///
+/// Responses without caching headers are stored only
+/// if caching was explicitly requested by the script
+fn is_storable(response: &Response, explicit: bool) -> bool {
+    // Only successful responses are cached
+    if response.status != 200 {
+        return false;
+    }
+
+    let control = CacheControl::from_headers(&response.headers);
+
+    if control.no_store || control.private {
+        return false;
+    }
+
+    // Response depends on something besides the request headers
+    if response.headers.get("vary").is_some_and(|vary| vary.trim() == "*") {
+        return false;
+    }
+
+    explicit || control.max_age > 0 || response.headers.contains_key("etag") || response.headers.contains_key("last-modified")
+}
+
+/// Get cached response of the request with given key
+pub fn get(key: &str) -> Option<CacheEntry> {
+    let path = get_entry_path(key);
+
+    let entry = std::fs::read(path).ok()
+        .and_then(|entry| serde_json::from_slice::<CacheEntry>(&entry).ok())?;
+
+    // Protect from hashes collisions
+    (entry.key == key).then_some(entry)
+}
+
+/// Store response in the cache if it's allowed by its headers
+///
+/// `explicit` means that caching was requested by the script
+pub fn store(key: &str, url: &str, response: &Response, explicit: bool) -> anyhow::Result<()> {
+    if !is_storable(response, explicit) {
+        return Ok(());
+    }
+
+    let control = CacheControl::from_headers(&response.headers);
+
+    let entry = CacheEntry {
+        key: key.to_string(),
+        url: url.to_string(),
+        status: response.status,
+        status_text: response.status_text.clone(),
+        headers: response.headers.clone(),
+        body: BASE64.encode(&response.body),
+        validated_at: now(),
+        max_age: control.max_age
+    };
+
+    write(&entry)
+}
+
+/// Update cached response after `304 Not Modified` response
+pub fn revalidate(mut entry: CacheEntry, response: &Response) -> anyhow::Result<CacheEntry> {
+    // Not modified response can contain updated validators
+    for header in ["etag", "last-modified", "cache-control", "date", "expires"] {
+        if let Some(value) = response.headers.get(header) {
+            entry.headers.insert(header.to_string(), value.clone());
+        }
+    }
+
+    entry.validated_at = now();
+    entry.max_age = CacheControl::from_headers(&entry.headers).max_age;
+
+    write(&entry)?;
+
+    Ok(entry)
+}
+
+fn write(entry: &CacheEntry) -> anyhow::Result<()> {
+    std::fs::create_dir_all(NETWORK_CACHE_FOLDER.as_path())?;
+
+    std::fs::write(get_entry_path(&entry.key), serde_json::to_vec(entry)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(headers: &[(&str, &str)]) -> HashMap<String, String> {
+        headers.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn response(status: i32, headers: HashMap<String, String>) -> Response {
+        Response {
+            url: String::from("https://example.com"),
+            status,
+            status_text: String::new(),
+            headers,
+            body: vec![]
+        }
+    }
+
+    #[test]
+    fn cache_control() {
+        let control = CacheControl::from_headers(&headers(&[("cache-control", "public, max-age=60")]));
+
+        assert_eq!(control, CacheControl { no_store: false, private: false, max_age: 60 });
+
+        let control = CacheControl::from_headers(&headers(&[("cache-control", "max-age=60, no-cache")]));
+
+        assert_eq!(control.max_age, 0);
+
+        let control = CacheControl::from_headers(&headers(&[("cache-control", "Private, max-age=60")]));
+
+        assert!(control.private);
+        assert!(!control.no_store);
+    }
+
+    #[test]
+    fn keys_include_headers() {
+        let url = "https://example.com";
+
+        assert_eq!(get_key(url, None), url);
+        assert_eq!(get_key(url, Some(&HashMap::new())), url);
+
+        let first = headers(&[("Accept", "text/html"), ("X-Lang", "en")]);
+        let second = headers(&[("x-lang", "en"), ("accept", "text/html")]);
+        let third = headers(&[("Accept", "text/html"), ("X-Lang", "de")]);
+
+        assert_eq!(get_key(url, Some(&first)), get_key(url, Some(&second)));
+        assert_ne!(get_key(url, Some(&first)), get_key(url, Some(&third)));
+        assert_ne!(get_key(url, Some(&first)), get_key(url, None));
+    }
+
+    #[test]
+    fn private_requests() {
+        assert!(!is_private_request(None));
+        assert!(!is_private_request(Some(&headers(&[("Accept", "text/html")]))));
+
+        assert!(is_private_request(Some(&headers(&[("Authorization", "Bearer token")]))));
+        assert!(is_private_request(Some(&headers(&[("cookie", "session=1")]))));
+    }
+
+    #[test]
+    fn storable_responses() {
+        assert!(is_storable(&response(200, headers(&[("cache-control", "max-age=60")])), false));
+        assert!(is_storable(&response(200, headers(&[("etag", "\"hash\"")])), false));
+        assert!(is_storable(&response(200, headers(&[("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")])), false));
+
+        // No caching headers
+        assert!(!is_storable(&response(200, HashMap::new()), false));
+        assert!(is_storable(&response(200, HashMap::new()), true));
+
+        assert!(!is_storable(&response(404, headers(&[("etag", "\"hash\"")])), true));
+        assert!(!is_storable(&response(200, headers(&[("cache-control", "no-store")])), true));
+        assert!(!is_storable(&response(200, headers(&[("cache-control", "private, max-age=60")])), true));
+        assert!(!is_storable(&response(200, headers(&[("etag", "\"hash\""), ("vary", "*")])), true));
+    }
+}
@@ -0,0 +1,176 @@
+use std::rc::Rc;
+use std::cell::Cell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Instant, Duration};
+
+use mlua::prelude::*;
+
+use crate::config;
+use crate::config::games::integrations::CallLimits;
+
+/// Amount of lua instructions between limits checks
+const CHECK_INSTRUCTIONS: u32 = 10000;
+
+/// Category of the integration script call
+///
+/// Every category has its own execution limits in the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CallCategory {
+    /// Card pictures, backgrounds and styles
+    Visual,
+
+    /// Games and addons info, statuses and launch options
+    Game,
+
+    /// Diff transitions hooks
+    Transitions
+}
+
+impl CallCategory {
+    /// Get execution limits of the category from the config
+    pub fn limits(&self) -> CallLimits {
+        let limits = config::get().games.integrations.limits;
+
+        match self {
+            Self::Visual      => limits.visual,
+            Self::Game        => limits.game,
+            Self::Transitions => limits.transitions
+        }
+    }
+}
+
+/// Error returned when the integration script call has exceeded its limits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LimitError {
+    Timeout {
+        game_name: String,
+        seconds: u64
+    },
+
+    Memory {
+        game_name: String,
+        megabytes: u64
+    }
+}
+
+impl std::fmt::Display for LimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Timeout { game_name, seconds } => write!(f, "{game_name} integration script timed out after {seconds} seconds"),
+            Self::Memory { game_name, megabytes } => write!(f, "{game_name} integration script exceeded memory limit of {megabytes} MB")
+        }
+    }
+}
+
+impl std::error::Error for LimitError {}
+
+/// Time limit of the current call
+///
+/// Stored in the lua app data while the call is processed
+struct CallDeadline {
+    deadline: Rc<Cell<Instant>>,
+
+    /// Set while the call is waiting for the user,
+    /// shared with the thread which has made the call
+    paused: Arc<AtomicBool>
+}
+
+/// Set lua hook which will stop the script execution
+/// when it exceeds given limits
+///
+/// Memory limit is enforced by the lua allocator if the engine supports it,
+/// so even a single big allocation fails. Otherwise (e.g. LuaJIT) the memory usage
+/// is only sampled by the hook every `CHECK_INSTRUCTIONS` instructions,
+/// and allocations made between the checks or inside of rust functions can exceed it.
+///
+/// Returned cell will contain the violated limit error.
+/// `paused` flag is set while the time limit is paused by `pause_limits`
+pub fn set_limits(lua: &Lua, game_name: &str, limits: CallLimits, paused: Arc<AtomicBool>) -> Rc<Cell<Option<LimitError>>> {
+    let violation = Rc::new(Cell::new(None));
+
+    let deadline = Rc::new(Cell::new(Instant::now() + Duration::from_secs(limits.timeout)));
+    let memory = limits.memory as usize * 1024 * 1024;
+
+    lua.set_app_data(CallDeadline {
+        deadline: deadline.clone(),
+        paused
+    });
+
+    if let Err(err) = lua.set_memory_limit(memory) {
+        tracing::trace!("Memory limit is only checked by the lua hook: {err}");
+    }
+
+    let game_name = game_name.to_string();
+    let hook_violation = violation.clone();
+
+    lua.set_hook(LuaHookTriggers::new().every_nth_instruction(CHECK_INSTRUCTIONS), move |lua, _| {
+        let error = if Instant::now() > deadline.get() {
+            LimitError::Timeout {
+                game_name: game_name.clone(),
+                seconds: limits.timeout
+            }
+        }
+
+        else if lua.used_memory() > memory {
+            LimitError::Memory {
+                game_name: game_name.clone(),
+                megabytes: limits.memory
+            }
+        }
+
+        else {
+            return Ok(());
+        };
+
+        hook_violation.set(Some(error.clone()));
+
+        Err(LuaError::external(error))
+    });
+
+    violation
+}
+
+/// Remove limits set by `set_limits`
+#[inline]
+pub fn remove_limits(lua: &Lua) {
+    lua.remove_hook();
+    lua.remove_app_data::<CallDeadline>();
+
+    let _ = lua.set_memory_limit(0);
+}
+
+/// Check if the error was caused by the lua allocator's memory limit
+pub fn is_memory_error(err: &anyhow::Error) -> bool {
+    fn is_lua_memory_error(err: &LuaError) -> bool {
+        match err {
+            LuaError::MemoryError(_) => true,
+            LuaError::CallbackError { cause, .. } => is_lua_memory_error(cause),
+
+            _ => false
+        }
+    }
+
+    err.chain().any(|err| err.downcast_ref::<LuaError>().map(is_lua_memory_error).unwrap_or(false))
+}
+
+/// Run callback without counting its execution time
+/// towards the current call's time limit
+///
+/// Used for functions waiting for the user's input
+pub fn pause_limits<T>(lua: &Lua, callback: impl FnOnce() -> T) -> T {
+    let Some((deadline, paused)) = lua.app_data_ref::<CallDeadline>().map(|call| (call.deadline.clone(), call.paused.clone())) else {
+        return callback();
+    };
+
+    paused.store(true, Ordering::Relaxed);
+
+    let started_at = Instant::now();
+    let result = callback();
+
+    deadline.set(deadline.get() + started_at.elapsed());
+
+    paused.store(false, Ordering::Relaxed);
+
+    result
+}
@@ -1,6 +1,10 @@
+use std::path::Path;
+use std::cmp::Ordering;
+
 use serde_json::Value as Json;
 
 use super::standards::IntegrationStandard;
+use super::sandbox::Capability;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Manifest {
@@ -10,58 +14,368 @@ pub struct Manifest {
 
     pub script_path: String,
     pub script_version: String,
-    pub script_standard: IntegrationStandard
+    pub script_standard: IntegrationStandard,
+    pub script_capabilities: Vec<Capability>,
+
+    /// v1 manifest doesn't declare capabilities,
+    /// so the default ones are granted
+    pub script_default_capabilities: bool,
+
+    /// Optional fields available since manifest v2
+    pub metadata: ManifestMetadata,
+
+    /// Minimal supported launcher version, inclusive
+    pub launcher_min_version: Option<String>,
+
+    /// Maximal supported launcher version, inclusive
+    pub launcher_max_version: Option<String>
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ManifestMetadata {
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    pub support: Option<String>,
+    pub tags: Vec<String>
 }
 
 impl Manifest {
     pub fn from_json(manifest: &Json) -> anyhow::Result<Self> {
-        match manifest.get("manifest_version").and_then(Json::as_str) {
-            Some("1") => {
-                let Some(game_manifest) = manifest.get("game") else {
-                    anyhow::bail!("Wrong manifest v1 structure: field `game` expected but wasn't presented");
-                };
-
-                let Some(script_manifest) = manifest.get("script") else {
-                    anyhow::bail!("Wrong manifest v1 structure: field `script` expected but wasn't presented");
-                };
-
-                Ok(Self {
-                    game_name: game_manifest.get("name")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `game.name` expected but wasn't presented"))?
-                        .to_string(),
-
-                    game_title: game_manifest.get("title")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `game.title` expected but wasn't presented"))?
-                        .to_string(),
-
-                    game_developer: game_manifest.get("developer")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `game.developer` expected but wasn't presented"))?
-                        .to_string(),
-
-                    script_path: script_manifest.get("path")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `script.path` expected but wasn't presented"))?
-                        .to_string(),
-
-                    script_version: script_manifest.get("version")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `script.version` expected but wasn't presented"))?
-                        .to_string(),
-
-                    script_standard: match script_manifest.get("standard").and_then(Json::as_str) {
-                        Some("1") => IntegrationStandard::V1,
-
-                        Some(version) => anyhow::bail!("Wrong manifest v1 structure: field `script.standard` containts unknown version: {version}"),
-                        None => anyhow::bail!("Wrong manifest v1 structure: field `script.standard` expected but wasn't presented")
-                    }
-                })
-            }
+        let version = match manifest.get("manifest_version").and_then(Json::as_str) {
+            Some(version @ ("1" | "2")) => version,
 
             Some(version) => anyhow::bail!("Unknown manifest version: {version}"),
             None => anyhow::bail!("Wrong manifest file structure")
+        };
+
+        let Some(game_manifest) = manifest.get("game") else {
+            anyhow::bail!("Wrong manifest v{version} structure: field `game` expected but wasn't presented");
+        };
+
+        let Some(script_manifest) = manifest.get("script") else {
+            anyhow::bail!("Wrong manifest v{version} structure: field `script` expected but wasn't presented");
+        };
+
+        let get_field = |manifest: &Json, name: &str, field: &str| -> anyhow::Result<String> {
+            manifest.get(name)
+                .and_then(Json::as_str)
+                .map(String::from)
+                .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `{field}` expected but wasn't presented"))
+        };
+
+        let get_capabilities = |capabilities: &Json, field: &str| -> anyhow::Result<Vec<Capability>> {
+            capabilities.as_array()
+                .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `{field}` must be an array"))?
+                .iter()
+                .map(|capability| {
+                    capability.as_str()
+                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `{field}` must contain strings"))
+                        .and_then(Capability::from_str)
+                })
+                .collect()
+        };
+
+        let mut result = Self {
+            game_name: get_field(game_manifest, "name", "game.name")?,
+            game_title: get_field(game_manifest, "title", "game.title")?,
+            game_developer: get_field(game_manifest, "developer", "game.developer")?,
+
+            script_path: get_field(script_manifest, "path", "script.path")?,
+            script_version: get_field(script_manifest, "version", "script.version")?,
+
+            script_standard: match script_manifest.get("standard").and_then(Json::as_str) {
+                Some("1") => IntegrationStandard::V1,
+                Some("2") => IntegrationStandard::V2,
+
+                Some(standard) => anyhow::bail!("Wrong manifest v{version} structure: field `script.standard` containts unknown version: {standard}"),
+                None => anyhow::bail!("Wrong manifest v{version} structure: field `script.standard` expected but wasn't presented")
+            },
+
+            script_capabilities: vec![],
+            script_default_capabilities: false,
+
+            metadata: ManifestMetadata::default(),
+
+            launcher_min_version: None,
+            launcher_max_version: None
+        };
+
+        match script_manifest.get("capabilities") {
+            Some(capabilities) => result.script_capabilities = get_capabilities(capabilities, "script.capabilities")?,
+
+            // Scripts made before capabilities were introduced
+            // get the network API and the scoped filesystem access
+            None if version == "1" => {
+                result.script_capabilities = vec![Capability::Network, Capability::Filesystem];
+                result.script_default_capabilities = true;
+            }
+
+            // v2 manifests must declare all the capabilities explicitly
+            None => ()
+        }
+
+        if version == "2" {
+            result.metadata = ManifestMetadata {
+                description: game_manifest.get("description")
+                    .and_then(Json::as_str)
+                    .map(String::from),
+
+                homepage: game_manifest.get("homepage")
+                    .and_then(Json::as_str)
+                    .map(String::from),
+
+                support: game_manifest.get("support")
+                    .and_then(Json::as_str)
+                    .map(String::from),
+
+                tags: game_manifest.get("tags")
+                    .and_then(Json::as_array)
+                    .map(|tags| tags.iter()
+                        .filter_map(|tag| tag.as_str().map(String::from))
+                        .collect()
+                    )
+                    .unwrap_or_default()
+            };
+
+            let launcher_manifest = manifest.get("launcher");
+
+            result.launcher_min_version = launcher_manifest
+                .and_then(|launcher| launcher.get("min_version"))
+                .and_then(Json::as_str)
+                .map(String::from);
+
+            result.launcher_max_version = launcher_manifest
+                .and_then(|launcher| launcher.get("max_version"))
+                .and_then(Json::as_str)
+                .map(String::from);
+        }
+
+        Ok(result)
+    }
+
+    #[inline]
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let manifest = std::fs::read(path)?;
+        let manifest = serde_json::from_slice::<Json>(&manifest)?;
+
+        Self::from_json(&manifest)
+    }
+
+    /// Check if the integration supports given launcher version
+    pub fn check_launcher_version(&self, version: impl AsRef<str>) -> anyhow::Result<()> {
+        let version = version.as_ref();
+
+        if let Some(min_version) = &self.launcher_min_version {
+            if compare_versions(version, min_version) == Ordering::Less {
+                anyhow::bail!("Integration requires launcher version {min_version} or newer");
+            }
+        }
+
+        if let Some(max_version) = &self.launcher_max_version {
+            if compare_versions(version, max_version) == Ordering::Greater {
+                anyhow::bail!("Integration supports launcher versions up to {max_version}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Split the version to its numeric parts and pre-release suffix
+///
+/// Build metadata is ignored, so `1.2.0-dev+1` is `[1, 2]` and `dev`
+fn parse_version(version: &str) -> (Vec<u64>, Option<&str>) {
+    let version = version.split('+')
+        .next()
+        .unwrap_or_default();
+
+    let (version, pre_release) = match version.split_once('-') {
+        Some((version, pre_release)) => (version, Some(pre_release)),
+        None => (version, None)
+    };
+
+    let mut parts = version.split('.')
+        .map(|part| part.parse::<u64>().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    // 1.2 == 1.2.0
+    while parts.last() == Some(&0) {
+        parts.pop();
+    }
+
+    (parts, pre_release)
+}
+
+/// Compare versions following semver precedence rules
+///
+/// Pre-release is lower than its release, so `1.0.0-beta < 1.0.0`
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a_parts, a_pre_release) = parse_version(a);
+    let (b_parts, b_pre_release) = parse_version(b);
+
+    a_parts.cmp(&b_parts).then_with(|| {
+        match (a_pre_release, b_pre_release) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+
+            (Some(a), Some(b)) => {
+                let a = a.split('.');
+                let b = b.split('.');
+
+                for (a, b) in a.clone().zip(b.clone()) {
+                    // Numeric identifiers are lower than alphanumeric ones
+                    let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+                        (Ok(a), Ok(b)) => a.cmp(&b),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => a.cmp(b)
+                    };
+
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+                }
+
+                a.count().cmp(&b.count())
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn manifest(version: &str, script: Json) -> Json {
+        json!({
+            "manifest_version": version,
+            "game": {
+                "name": "example",
+                "title": "Example",
+                "developer": "Example developer"
+            },
+            "script": script
+        })
+    }
+
+    #[test]
+    fn declared_capabilities() -> anyhow::Result<()> {
+        for version in ["1", "2"] {
+            let manifest = Manifest::from_json(&manifest(version, json!({
+                "path": "script.lua",
+                "version": "1",
+                "standard": "1",
+                "capabilities": ["network", "process"]
+            })))?;
+
+            assert_eq!(manifest.script_capabilities, vec![Capability::Network, Capability::Process]);
+            assert!(!manifest.script_default_capabilities);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn undeclared_capabilities() -> anyhow::Result<()> {
+        let script = json!({
+            "path": "script.lua",
+            "version": "1",
+            "standard": "1"
+        });
+
+        let v1 = Manifest::from_json(&manifest("1", script.clone()))?;
+        let v2 = Manifest::from_json(&manifest("2", script))?;
+
+        assert_eq!(v1.script_capabilities, vec![Capability::Network, Capability::Filesystem]);
+        assert!(v1.script_default_capabilities);
+
+        assert!(v2.script_capabilities.is_empty());
+        assert!(!v2.script_default_capabilities);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_capabilities() {
+        for capabilities in [json!(["legacy"]), json!("network"), json!([1])] {
+            let manifest = manifest("1", json!({
+                "path": "script.lua",
+                "version": "1",
+                "standard": "1",
+                "capabilities": capabilities
+            }));
+
+            assert!(Manifest::from_json(&manifest).is_err());
+        }
+    }
+
+    #[test]
+    fn manifest_versions() {
+        let script = json!({
+            "path": "script.lua",
+            "version": "1",
+            "standard": "1"
+        });
+
+        assert!(Manifest::from_json(&manifest("1", script.clone())).is_ok());
+        assert!(Manifest::from_json(&manifest("2", script.clone())).is_ok());
+
+        assert!(Manifest::from_json(&manifest("3", script.clone())).is_err());
+        assert!(Manifest::from_json(&json!({ "game": {}, "script": script })).is_err());
+    }
+
+    #[test]
+    fn launcher_versions() -> anyhow::Result<()> {
+        let mut manifest = manifest("2", json!({
+            "path": "script.lua",
+            "version": "1",
+            "standard": "2"
+        }));
+
+        manifest["launcher"] = json!({
+            "min_version": "1.2.0",
+            "max_version": "2.0.0"
+        });
+
+        let manifest = Manifest::from_json(&manifest)?;
+
+        assert_eq!(manifest.launcher_min_version.as_deref(), Some("1.2.0"));
+        assert_eq!(manifest.launcher_max_version.as_deref(), Some("2.0.0"));
+
+        assert!(manifest.check_launcher_version("1.2.0").is_ok());
+        assert!(manifest.check_launcher_version("1.10.0").is_ok());
+        assert!(manifest.check_launcher_version("2.0.0").is_ok());
+
+        assert!(manifest.check_launcher_version("1.1.9").is_err());
+        assert!(manifest.check_launcher_version("1.2.0-beta").is_err());
+        assert!(manifest.check_launcher_version("2.0.1").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn semver_precedence() {
+        assert_eq!(compare_versions("1.2", "1.2.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.2.0+build", "1.2.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.10.0", "1.9.0"), Ordering::Greater);
+
+        // Example from the semver specification
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0"
+        ];
+
+        for pair in versions.windows(2) {
+            assert_eq!(compare_versions(pair[0], pair[1]), Ordering::Less, "{} < {}", pair[0], pair[1]);
         }
     }
 }
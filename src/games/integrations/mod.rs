@@ -1,10 +1,13 @@
 use std::path::{Path, PathBuf};
 
-use serde_json::Value as Json;
-
 pub mod manifest;
 pub mod standards;
 pub mod driver;
+pub mod sandbox;
+pub mod limits;
+pub mod signature;
+pub mod fixtures;
+pub mod http_cache;
 
 use manifest::Manifest;
 use driver::Driver;
@@ -12,15 +15,20 @@ use driver::Driver;
 #[derive(Debug)]
 pub struct Game {
     pub manifest: Manifest,
-    pub driver: Driver
+    pub driver: Driver,
+
+    /// Integration is symlinked from the local development source
+    pub is_local: bool
 }
 
 impl Game {
+    #[inline]
     pub fn new(manifest_path: impl AsRef<Path>) -> anyhow::Result<Self> {
-        let manifest = std::fs::read(manifest_path.as_ref())?;
-        let manifest = serde_json::from_slice::<Json>(&manifest)?;
-        let manifest = Manifest::from_json(&manifest)?;
+        Self::from_manifest(Manifest::from_file(manifest_path.as_ref())?, manifest_path)
+    }
 
+    /// Load integration script of already parsed manifest
+    pub fn from_manifest(manifest: Manifest, manifest_path: impl AsRef<Path>) -> anyhow::Result<Self> {
         let script_path = PathBuf::from(&manifest.script_path);
 
         let script_path = if script_path.is_absolute() {
@@ -32,15 +40,29 @@ impl Game {
                 .unwrap_or(script_path)
         };
 
+        if manifest.script_default_capabilities {
+            tracing::warn!(
+                game = %manifest.game_name,
+                "Integration manifest doesn't declare `script.capabilities`, so only `network` and `filesystem` capabilities are granted. Undeclared capabilities are deprecated and will be refused in future versions"
+            );
+        }
+
         let driver = Driver::new(
             &manifest.game_name,
             manifest.script_standard,
+            &manifest.script_capabilities,
             std::fs::read_to_string(script_path)?
         )?;
 
+        let is_local = manifest_path.as_ref()
+            .parent()
+            .map(Path::is_symlink)
+            .unwrap_or(false);
+
         Ok(Self {
             manifest,
-            driver
+            driver,
+            is_local
         })
     }
 }
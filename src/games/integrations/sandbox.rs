@@ -0,0 +1,346 @@
+use std::path::{Path, PathBuf, Component};
+use std::os::unix::ffi::OsStrExt;
+
+use mlua::prelude::*;
+
+use crate::config;
+use crate::LAUNCHER_FOLDER;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// Access to the `v1_network_fetch` API
+    Network,
+
+    /// Access to the `io` library and files removing,
+    /// limited by the game folders
+    Filesystem,
+
+    /// Access to `os.execute`, `os.getenv` and `io.popen`
+    Process
+}
+
+impl Capability {
+    pub fn from_str(value: impl AsRef<str>) -> anyhow::Result<Self> {
+        match value.as_ref() {
+            "network"    => Ok(Self::Network),
+            "filesystem" => Ok(Self::Filesystem),
+            "process"    => Ok(Self::Process),
+
+            _ => anyhow::bail!("Unknown integration capability: '{}'", value.as_ref())
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Network    => "network",
+            Self::Filesystem => "filesystem",
+            Self::Process    => "process"
+        }
+    }
+}
+
+/// Globals which are removed from every integration script
+const STRIPPED_GLOBALS: &[&str] = &[
+    "dofile",
+    "loadfile",
+    "load",
+    "loadstring",
+    "require",
+    "module",
+    "package",
+    "debug",
+    "ffi",
+    "jit"
+];
+
+/// `os` library functions available without any capability
+const SAFE_OS_FUNCTIONS: &[&str] = &[
+    "time",
+    "clock",
+    "date",
+    "difftime"
+];
+
+/// Create lua engine with stripped standard library
+/// and apply given capabilities to it
+pub fn create_sandbox(game_name: impl AsRef<str>, capabilities: &[Capability]) -> anyhow::Result<Lua> {
+    let lua = Lua::new_with(
+        StdLib::TABLE | StdLib::STRING | StdLib::MATH | StdLib::BIT | StdLib::IO | StdLib::OS,
+        LuaOptions::new()
+    )?;
+
+    apply_sandbox(&lua, game_name.as_ref(), capabilities)?;
+
+    Ok(lua)
+}
+
+fn apply_sandbox(lua: &Lua, game_name: &str, capabilities: &[Capability]) -> LuaResult<()> {
+    let globals = lua.globals();
+
+    for name in STRIPPED_GLOBALS {
+        globals.raw_remove(*name)?;
+    }
+
+    let io = globals.get::<_, LuaTable>("io")?;
+    let os = globals.get::<_, LuaTable>("os")?;
+
+    let sandbox_io = lua.create_table()?;
+    let sandbox_os = lua.create_table()?;
+
+    for name in SAFE_OS_FUNCTIONS {
+        sandbox_os.set(*name, os.get::<_, LuaFunction>(*name)?)?;
+    }
+
+    if capabilities.contains(&Capability::Filesystem) {
+        sandbox_io.set("open", scoped_function(lua, game_name, "io.open", 1, io.get("open")?)?)?;
+        sandbox_io.set("lines", scoped_function(lua, game_name, "io.lines", 1, io.get("lines")?)?)?;
+
+        sandbox_os.set("remove", scoped_function(lua, game_name, "os.remove", 1, os.get("remove")?)?)?;
+        sandbox_os.set("rename", scoped_function(lua, game_name, "os.rename", 2, os.get("rename")?)?)?;
+    }
+
+    else {
+        sandbox_io.set("open", refused_function(lua, Capability::Filesystem, "io.open")?)?;
+        sandbox_io.set("lines", refused_function(lua, Capability::Filesystem, "io.lines")?)?;
+
+        sandbox_os.set("remove", refused_function(lua, Capability::Filesystem, "os.remove")?)?;
+        sandbox_os.set("rename", refused_function(lua, Capability::Filesystem, "os.rename")?)?;
+    }
+
+    if capabilities.contains(&Capability::Process) {
+        sandbox_io.set("popen", io.get::<_, LuaFunction>("popen")?)?;
+
+        sandbox_os.set("execute", os.get::<_, LuaFunction>("execute")?)?;
+        sandbox_os.set("getenv", os.get::<_, LuaFunction>("getenv")?)?;
+    }
+
+    else {
+        sandbox_io.set("popen", refused_function(lua, Capability::Process, "io.popen")?)?;
+
+        sandbox_os.set("execute", refused_function(lua, Capability::Process, "os.execute")?)?;
+        sandbox_os.set("getenv", refused_function(lua, Capability::Process, "os.getenv")?)?;
+    }
+
+    globals.set("io", sandbox_io)?;
+    globals.set("os", sandbox_os)?;
+
+    Ok(())
+}
+
+/// Create lua function which always fails because
+/// the integration hasn't declared needed capability
+pub fn refused_function<'lua>(lua: &'lua Lua, capability: Capability, name: &str) -> LuaResult<LuaFunction<'lua>> {
+    let name = name.to_string();
+
+    lua.create_function(move |_, _: LuaMultiValue| -> LuaResult<()> {
+        Err(refused_error(capability, &name))
+    })
+}
+
+/// Create error returned when the integration
+/// hasn't declared needed capability
+pub fn refused_error(capability: Capability, name: &str) -> LuaError {
+    LuaError::RuntimeError(format!(
+        "Calling `{name}` requires `{}` capability which is not declared in the integration manifest",
+        capability.to_str()
+    ))
+}
+
+/// Wrap lua function so its first `paths` arguments
+/// are verified to be located within the game folders
+///
+/// Resolved paths are passed to the wrapped function instead
+/// of the given ones, so they can't be resolved differently
+fn scoped_function<'lua>(lua: &'lua Lua, game_name: &str, name: &str, paths: usize, function: LuaFunction<'lua>) -> LuaResult<LuaFunction<'lua>> {
+    let function = lua.create_registry_value(function)?;
+
+    let game_name = game_name.to_string();
+    let name = name.to_string();
+
+    lua.create_function(move |lua, args: LuaMultiValue| {
+        let mut args = args.into_vec();
+
+        for value in args.iter_mut().take(paths) {
+            let LuaValue::String(path) = value else {
+                return Err(LuaError::RuntimeError(format!("`{name}` expects path string as an argument")));
+            };
+
+            let path = path.to_str()?;
+
+            let Some(resolved) = resolve_allowed_path(&game_name, path) else {
+                return Err(LuaError::RuntimeError(format!("`{name}` is not allowed to access `{path}`: it's located outside of the game folders")));
+            };
+
+            *value = LuaValue::String(lua.create_string(resolved.as_os_str().as_bytes())?);
+        }
+
+        lua.registry_value::<LuaFunction>(&function)?
+            .call::<_, LuaMultiValue>(LuaMultiValue::from_vec(args))
+    })
+}
+
+/// Get list of folders the integration script can access
+///
+/// Empty and relative paths from the config are ignored
+pub fn get_allowed_roots(game_name: impl AsRef<str>) -> Vec<PathBuf> {
+    let config = config::get();
+
+    let mut roots = vec![
+        LAUNCHER_FOLDER.join("games").join(game_name.as_ref()),
+        config.general.transitions.path.clone()
+    ];
+
+    for paths in config.games.get_game_edition_paths(game_name.as_ref()).into_values() {
+        roots.push(paths.game);
+        roots.push(paths.addons);
+        roots.push(paths.deployment);
+    }
+
+    roots.retain(|root| root.is_absolute());
+
+    roots
+}
+
+/// Check if the path is located within the game folders
+#[inline]
+pub fn is_path_allowed(game_name: impl AsRef<str>, path: impl AsRef<Path>) -> bool {
+    resolve_allowed_path(game_name, path).is_some()
+}
+
+/// Resolve the path if it's located within the game folders
+///
+/// Symlinks are resolved, so links inside of the game folders
+/// can't be used to access files outside of them. Only the
+/// returned path should be used to access the filesystem
+pub fn resolve_allowed_path(game_name: impl AsRef<str>, path: impl AsRef<Path>) -> Option<PathBuf> {
+    resolve_within(path, &get_allowed_roots(game_name))
+}
+
+/// Resolve the path if it's located within one of the given roots
+fn resolve_within(path: impl AsRef<Path>, roots: &[PathBuf]) -> Option<PathBuf> {
+    if !path.as_ref().is_absolute() {
+        return None;
+    }
+
+    let path = resolve_path(path)?;
+
+    roots.iter()
+        .filter_map(resolve_path)
+        .any(|root| path.starts_with(root))
+        .then_some(path)
+}
+
+/// Resolve the path component by component, the same way the OS does
+///
+/// Every existing component is canonicalized before the next one is applied,
+/// so `..` goes to the parent of the symlink's target rather than the parent
+/// of the symlink itself. Missing components are appended as is, so paths
+/// of the files which are going to be created can be verified as well.
+///
+/// Returns `None` if the path goes through a broken symlink because its
+/// target can be created outside of the game folders, or if `..` follows
+/// a missing component
+fn resolve_path(path: impl AsRef<Path>) -> Option<PathBuf> {
+    let mut resolved = PathBuf::new();
+    let mut is_missing = false;
+
+    for component in path.as_ref().components() {
+        match component {
+            Component::CurDir => (),
+
+            Component::ParentDir => {
+                if is_missing {
+                    return None;
+                }
+
+                resolved.pop();
+            }
+
+            Component::Normal(name) => {
+                resolved.push(name);
+
+                if !is_missing {
+                    match resolved.canonicalize() {
+                        Ok(canonical) => resolved = canonical,
+
+                        // Path exists but can't be resolved
+                        Err(_) if resolved.symlink_metadata().is_ok() => return None,
+
+                        Err(_) => is_missing = true
+                    }
+                }
+            }
+
+            component => resolved.push(component)
+        }
+    }
+
+    Some(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create empty temp folder for the test
+    fn temp_folder(name: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("anime-games-launcher-sandbox-test-{}-{name}", std::process::id()));
+
+        let _ = std::fs::remove_dir_all(&path);
+
+        std::fs::create_dir_all(&path).unwrap();
+
+        path.canonicalize().unwrap()
+    }
+
+    #[test]
+    fn relative_paths_are_refused() {
+        let root = temp_folder("relative");
+
+        assert_eq!(resolve_within("game/file", &[root.clone()]), None);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn paths_within_roots() {
+        let root = temp_folder("within");
+
+        std::fs::create_dir(root.join("game")).unwrap();
+
+        let roots = [root.join("game")];
+
+        assert_eq!(resolve_within(root.join("game/file"), &roots), Some(root.join("game/file")));
+        assert_eq!(resolve_within(root.join("game/new/file"), &roots), Some(root.join("game/new/file")));
+        assert_eq!(resolve_within(root.join("game/./file"), &roots), Some(root.join("game/file")));
+
+        assert_eq!(resolve_within(root.join("game/../file"), &roots), None);
+        assert_eq!(resolve_within(root.join("game/new/../../file"), &roots), None);
+        assert_eq!(resolve_within(root.join("other/file"), &roots), None);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn symlinks_are_resolved() {
+        let root = temp_folder("symlinks");
+
+        std::fs::create_dir_all(root.join("game")).unwrap();
+        std::fs::create_dir_all(root.join("outside/folder")).unwrap();
+
+        std::os::unix::fs::symlink(root.join("outside/folder"), root.join("game/link")).unwrap();
+        std::os::unix::fs::symlink(root.join("missing"), root.join("game/broken")).unwrap();
+
+        let roots = [root.join("game")];
+
+        // `game/link/..` is `outside` for the OS, not `game`
+        assert_eq!(resolve_within(root.join("game/link/../x"), &roots), None);
+        assert_eq!(resolve_within(root.join("game/link/file"), &roots), None);
+        assert_eq!(resolve_within(root.join("game/broken"), &roots), None);
+
+        assert_eq!(resolve_path(root.join("game/link/../x")), Some(root.join("outside/x")));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use ed25519_dalek::{VerifyingKey, Signature, Verifier};
+
+/// Decode base64 encoded ed25519 public key
+pub fn decode_public_key(key: impl AsRef<str>) -> anyhow::Result<VerifyingKey> {
+    let bytes = BASE64.decode(key.as_ref().trim())?;
+
+    let Ok(bytes) = <[u8; 32]>::try_from(bytes.as_slice()) else {
+        anyhow::bail!("Public key must be 32 bytes long, got {}", bytes.len());
+    };
+
+    Ok(VerifyingKey::from_bytes(&bytes)?)
+}
+
+/// Decode base64 encoded detached ed25519 signature
+pub fn decode_signature(signature: impl AsRef<[u8]>) -> anyhow::Result<Signature> {
+    let text = String::from_utf8_lossy(signature.as_ref());
+    let bytes = BASE64.decode(text.trim())?;
+
+    let Ok(bytes) = <[u8; 64]>::try_from(bytes.as_slice()) else {
+        anyhow::bail!("Signature must be 64 bytes long, got {}", bytes.len());
+    };
+
+    Ok(Signature::from_bytes(&bytes))
+}
+
+/// Verify that the data is signed by one of the given public keys
+pub fn verify(data: impl AsRef<[u8]>, signature: impl AsRef<[u8]>, keys: &[String]) -> anyhow::Result<()> {
+    let signature = decode_signature(signature)?;
+
+    for key in keys {
+        match decode_public_key(key) {
+            Ok(key) => {
+                if key.verify(data.as_ref(), &signature).is_ok() {
+                    return Ok(());
+                }
+            }
+
+            Err(err) => tracing::warn!("Failed to decode trusted key {key}: {err}")
+        }
+    }
+
+    anyhow::bail!("Signature doesn't match any trusted key")
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{SigningKey, Signer};
+
+    use super::*;
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn public_key(key: &SigningKey) -> String {
+        BASE64.encode(key.verifying_key().as_bytes())
+    }
+
+    fn sign(key: &SigningKey, data: &[u8]) -> String {
+        BASE64.encode(key.sign(data).to_bytes())
+    }
+
+    #[test]
+    fn valid_signature() -> anyhow::Result<()> {
+        let key = signing_key(1);
+        let signature = sign(&key, b"integration");
+
+        verify(b"integration", &signature, &[public_key(&key)])?;
+
+        // Signature files can end with a new line
+        verify(b"integration", format!("{signature}\n"), &[public_key(&key)])?;
+
+        // Any of the trusted keys can match
+        verify(b"integration", &signature, &[public_key(&signing_key(2)), public_key(&key)])?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_signature() {
+        let key = signing_key(1);
+        let signature = sign(&key, b"integration");
+
+        assert!(verify(b"modified integration", &signature, &[public_key(&key)]).is_err());
+        assert!(verify(b"integration", &signature, &[public_key(&signing_key(2))]).is_err());
+        assert!(verify(b"integration", &signature, &[]).is_err());
+
+        // Broken keys are skipped
+        assert!(verify(b"integration", &signature, &[String::from("invalid"), public_key(&key)]).is_ok());
+    }
+
+    #[test]
+    fn malformed_keys_and_signatures() {
+        assert!(decode_public_key("invalid base64").is_err());
+        assert!(decode_public_key(BASE64.encode([0; 16])).is_err());
+
+        assert!(decode_signature("invalid base64").is_err());
+        assert!(decode_signature(BASE64.encode([0; 32])).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::path::{Path, Component};
+
+use mlua::prelude::*;
+
+use anime_game_core::archive;
+use anime_game_core::updater::UpdaterExt;
+
+use super::IntegrationStandard;
+use super::filesystem::resolve_path;
+
+use crate::games::integrations::sandbox::{self, Capability};
+
+/// Functions of the archives API
+const FUNCTIONS: &[&str] = &[
+    "archive_list",
+    "archive_extract"
+];
+
+/// Register archives API in the lua engine
+///
+/// Both archive and extraction paths are verified to be
+/// located within the game, addons and transitions folders
+pub fn register(lua: &Lua, standard: IntegrationStandard, game_name: &str, capabilities: &[Capability]) -> LuaResult<()> {
+    let globals = lua.globals();
+
+    if !capabilities.contains(&Capability::Filesystem) {
+        for name in FUNCTIONS {
+            let name = standard.function_name(name);
+
+            globals.set(name.as_str(), sandbox::refused_function(lua, Capability::Filesystem, &name)?)?;
+        }
+
+        return Ok(());
+    }
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("archive_list"), lua.create_function(move |lua, path: String| {
+        let Some(entries) = archive::get_entries(resolve_path(&game, &path)?) else {
+            return Err(LuaError::RuntimeError(format!("Unsupported archive format: `{path}`")));
+        };
+
+        let entries = entries.map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        let table = lua.create_table()?;
+
+        for entry in entries {
+            let entry_table = lua.create_table()?;
+
+            entry_table.set("path", entry.path.to_string_lossy().to_string())?;
+            entry_table.set("size", entry.size)?;
+
+            table.raw_push(entry_table)?;
+        }
+
+        Ok(table)
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("archive_extract"), lua.create_function(move |_, (path, folder): (String, String)| {
+        let archive_path = resolve_path(&game, &path)?;
+        let folder = resolve_path(&game, &folder)?;
+
+        let Some(entries) = archive::get_entries(archive_path.clone()) else {
+            return Err(LuaError::RuntimeError(format!("Unsupported archive format: `{path}`")));
+        };
+
+        let entries = entries.map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        // Entries can't be extracted outside of the given folder
+        for entry in entries {
+            let escapes = entry.path.components()
+                .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir));
+
+            if escapes {
+                return Err(LuaError::RuntimeError(format!("Archive `{path}` contains entry outside of the extraction folder: `{}`", entry.path.to_string_lossy())));
+            }
+        }
+
+        let Some(updater) = archive::extract(archive_path, folder.clone()) else {
+            return Err(LuaError::RuntimeError(format!("Failed to extract files from the archive: `{path}`")));
+        };
+
+        updater.wait()
+            .map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        remove_escaping_symlinks(&game, &folder)
+            .map_err(LuaError::external)
+    })?)?;
+
+    Ok(())
+}
+
+/// Remove extracted symlinks which point outside of the game folders
+fn remove_escaping_symlinks(game_name: &str, folder: &Path) -> std::io::Result<()> {
+    for entry in folder.read_dir()? {
+        let entry = entry?;
+
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+
+        if file_type.is_symlink() {
+            let target = std::fs::read_link(&path)?;
+
+            if !sandbox::is_path_allowed(game_name, folder.join(target)) {
+                tracing::warn!(game = game_name, "Removing extracted symlink pointing outside of the game folders: {path:?}");
+
+                std::fs::remove_file(&path)?;
+            }
+        }
+
+        else if file_type.is_dir() {
+            remove_escaping_symlinks(game_name, &path)?;
+        }
+    }
+
+    Ok(())
+}
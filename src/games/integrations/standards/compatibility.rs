@@ -0,0 +1,278 @@
+use mlua::prelude::*;
+
+use super::IntegrationStandard;
+use super::game::StatusSeverity;
+
+/// Architecture suffixes of the component names, e.g. `lutris-GE-Proton8-26-x86_64`
+const ARCH_SUFFIXES: &[&str] = &[
+    "x86_64", "amd64", "x86", "i386", "i686",
+    "aarch64", "arm64",
+    "win64", "win32", "wow64"
+];
+
+/// Remove architecture suffixes which contain digits
+/// but are not a part of the component version
+fn strip_arch_suffixes(mut version: &str) -> &str {
+    'strip: loop {
+        for suffix in ARCH_SUFFIXES {
+            let Some(index) = version.len().checked_sub(suffix.len()) else {
+                continue;
+            };
+
+            if !version.is_char_boundary(index) || !version[index..].eq_ignore_ascii_case(suffix) {
+                continue;
+            }
+
+            if let Some(stripped) = version[..index].strip_suffix(['-', '_', '.']) {
+                version = stripped;
+
+                continue 'strip;
+            }
+        }
+
+        return version;
+    }
+}
+
+/// Parse numeric parts of the component version
+///
+/// Non-numeric parts and architecture suffixes are ignored,
+/// so `lutris-GE-Proton8-26-x86_64` is `[8, 26]`
+fn parse_version(version: &str) -> Vec<u64> {
+    strip_arch_suffixes(version).split(|char: char| !char.is_ascii_digit())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<u64>().unwrap_or(0))
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentRule {
+    /// Components build name, any if not specified
+    pub build: Option<String>,
+
+    /// Exact component version
+    pub version: Option<String>,
+
+    /// Inclusive versions range
+    pub min_version: Option<String>,
+    pub max_version: Option<String>,
+
+    /// Why this rule exists, displayed to the user
+    pub reason: Option<String>
+}
+
+impl ComponentRule {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                Ok(Self {
+                    build: table.get::<_, Option<String>>("build")?,
+                    version: table.get::<_, Option<String>>("version")?,
+                    min_version: table.get::<_, Option<String>>("min_version")?,
+                    max_version: table.get::<_, Option<String>>("max_version")?,
+                    reason: table.get::<_, Option<String>>("reason")?
+                })
+            }
+        }
+    }
+
+    /// Check if the rule matches given component build and version
+    pub fn matches(&self, build: &str, version: &str) -> bool {
+        if let Some(rule_build) = &self.build {
+            if rule_build != build {
+                return false;
+            }
+        }
+
+        if let Some(rule_version) = &self.version {
+            if rule_version != version {
+                return false;
+            }
+        }
+
+        let parsed_version = parse_version(version);
+
+        if let Some(min_version) = &self.min_version {
+            if parsed_version < parse_version(min_version) {
+                return false;
+            }
+        }
+
+        if let Some(max_version) = &self.max_version {
+            if parsed_version > parse_version(max_version) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompatibilityIssue {
+    /// Component doesn't match any supported rule
+    Unsupported,
+
+    /// Component matches a blacklisted rule
+    Blacklisted {
+        reason: Option<String>
+    }
+}
+
+impl CompatibilityIssue {
+    #[inline]
+    pub fn severity(&self) -> StatusSeverity {
+        match self {
+            Self::Unsupported      => StatusSeverity::Warning,
+            Self::Blacklisted {..} => StatusSeverity::Critical
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentCompatibility {
+    /// If not empty, only matching components are supported
+    pub supported: Vec<ComponentRule>,
+
+    pub blacklisted: Vec<ComponentRule>
+}
+
+impl ComponentCompatibility {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                let get_rules = |name: &str| -> anyhow::Result<Vec<ComponentRule>> {
+                    match table.get::<_, Option<LuaTable>>(name)? {
+                        Some(rules) => rules.sequence_values::<LuaTable>()
+                            .map(|rule| ComponentRule::from_table(rule?, standard))
+                            .collect(),
+
+                        None => Ok(vec![])
+                    }
+                };
+
+                Ok(Self {
+                    supported: get_rules("supported")?,
+                    blacklisted: get_rules("blacklisted")?
+                })
+            }
+        }
+    }
+
+    /// Check given component build and version
+    ///
+    /// Blacklisted rules have priority over supported ones
+    pub fn check(&self, build: &str, version: &str) -> Option<CompatibilityIssue> {
+        if let Some(rule) = self.blacklisted.iter().find(|rule| rule.matches(build, version)) {
+            return Some(CompatibilityIssue::Blacklisted {
+                reason: rule.reason.clone()
+            });
+        }
+
+        if !self.supported.is_empty() && !self.supported.iter().any(|rule| rule.matches(build, version)) {
+            return Some(CompatibilityIssue::Unsupported);
+        }
+
+        None
+    }
+}
+
+/// Wine and DXVK builds and versions supported by the game edition
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Compatibility {
+    pub wine: ComponentCompatibility,
+    pub dxvk: ComponentCompatibility
+}
+
+impl Compatibility {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                Ok(Self {
+                    wine: match table.get::<_, Option<LuaTable>>("wine")? {
+                        Some(wine) => ComponentCompatibility::from_table(wine, standard)?,
+                        None => ComponentCompatibility::default()
+                    },
+
+                    dxvk: match table.get::<_, Option<LuaTable>>("dxvk")? {
+                        Some(dxvk) => ComponentCompatibility::from_table(dxvk, standard)?,
+                        None => ComponentCompatibility::default()
+                    }
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(min_version: Option<&str>, max_version: Option<&str>) -> ComponentRule {
+        ComponentRule {
+            min_version: min_version.map(String::from),
+            max_version: max_version.map(String::from),
+
+            ..ComponentRule::default()
+        }
+    }
+
+    #[test]
+    fn parse_versions() {
+        assert_eq!(parse_version("GE-Proton8-26"), vec![8, 26]);
+        assert_eq!(parse_version("lutris-GE-Proton8-26-x86_64"), vec![8, 26]);
+        assert_eq!(parse_version("wine-9.0-amd64-wow64"), vec![9, 0]);
+        assert_eq!(parse_version("2.3.1"), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn empty_rule_matches_anything() {
+        assert!(ComponentRule::default().matches("wine-ge-proton", "lutris-GE-Proton8-26-x86_64"));
+    }
+
+    #[test]
+    fn build_and_exact_version() {
+        let rule = ComponentRule {
+            build: Some(String::from("wine-ge-proton")),
+            version: Some(String::from("lutris-GE-Proton8-26-x86_64")),
+
+            ..ComponentRule::default()
+        };
+
+        assert!(rule.matches("wine-ge-proton", "lutris-GE-Proton8-26-x86_64"));
+        assert!(!rule.matches("wine-staging", "lutris-GE-Proton8-26-x86_64"));
+        assert!(!rule.matches("wine-ge-proton", "lutris-GE-Proton8-25-x86_64"));
+    }
+
+    #[test]
+    fn inclusive_versions_range() {
+        let rule = rule(Some("8.0"), Some("8.26"));
+
+        assert!(rule.matches("wine-ge-proton", "lutris-GE-Proton8-0-x86_64"));
+        assert!(rule.matches("wine-ge-proton", "lutris-GE-Proton8-26-x86_64"));
+        assert!(rule.matches("wine-ge-proton", "lutris-GE-Proton8-13-x86_64"));
+
+        assert!(!rule.matches("wine-ge-proton", "lutris-GE-Proton7-55-x86_64"));
+        assert!(!rule.matches("wine-ge-proton", "lutris-GE-Proton8-27-x86_64"));
+        assert!(!rule.matches("wine-ge-proton", "lutris-GE-Proton9-1-x86_64"));
+    }
+
+    #[test]
+    fn blacklisted_have_priority() {
+        let compatibility = ComponentCompatibility {
+            supported: vec![rule(Some("8.0"), None)],
+            blacklisted: vec![ComponentRule {
+                reason: Some(String::from("Crashes on startup")),
+
+                ..rule(Some("8.26"), Some("8.26"))
+            }]
+        };
+
+        assert_eq!(compatibility.check("wine-ge-proton", "lutris-GE-Proton8-25-x86_64"), None);
+        assert_eq!(compatibility.check("wine-ge-proton", "lutris-GE-Proton7-55-x86_64"), Some(CompatibilityIssue::Unsupported));
+
+        assert_eq!(compatibility.check("wine-ge-proton", "lutris-GE-Proton8-26-x86_64"), Some(CompatibilityIssue::Blacklisted {
+            reason: Some(String::from("Crashes on startup"))
+        }));
+    }
+}
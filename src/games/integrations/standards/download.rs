@@ -13,7 +13,7 @@ pub struct Download {
 impl Download {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     version: table.get::<_, String>("version")?,
                     edition: table.get::<_, String>("edition")?,
@@ -25,7 +25,7 @@ impl Download {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
 
                 table.set("version", self.version.clone())?;
@@ -0,0 +1,155 @@
+use mlua::prelude::*;
+
+use super::IntegrationStandard;
+
+/// Structured error thrown by the integration script with `v1_error` function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    /// Error code, e.g. `api-unavailable`
+    pub code: String,
+
+    /// Error message given by the script
+    pub message: String,
+
+    /// Optional error details given by the script
+    pub details: Option<String>,
+
+    /// Raw lua error with stack traceback
+    pub trace: String
+}
+
+impl ScriptError {
+    /// Get localized error message
+    ///
+    /// Known error codes are translated by the launcher,
+    /// otherwise the script's message is returned
+    pub fn localized_message(&self) -> String {
+        use std::collections::HashMap;
+
+        use fluent_templates::Loader;
+        use fluent_templates::fluent_bundle::FluentValue;
+
+        let args = HashMap::from([
+            ("message", FluentValue::from(self.message.as_str()))
+        ]);
+
+        crate::i18n::LOCALES
+            .lookup_complete(unsafe { crate::i18n::get_lang() }, &format!("script-error-{}", self.code), Some(&args))
+            .unwrap_or_else(|| self.message.clone())
+    }
+
+    /// Get error trace, including the details given by the script
+    pub fn full_trace(&self) -> String {
+        let mut trace = format!("Code: {}\nMessage: {}", self.code, self.message);
+
+        if let Some(details) = &self.details {
+            trace = format!("{trace}\nDetails: {details}");
+        }
+
+        format!("{trace}\n\n{}", self.trace)
+    }
+}
+
+impl std::fmt::Display for ScriptError {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.localized_message())
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// Find script error in the lua error's causes
+fn find_script_error(err: &LuaError) -> Option<&ScriptError> {
+    match err {
+        LuaError::CallbackError { cause, .. } => find_script_error(cause),
+        LuaError::WithContext { cause, .. } => find_script_error(cause),
+        LuaError::ExternalError(err) => err.downcast_ref::<ScriptError>(),
+
+        _ => None
+    }
+}
+
+/// Replace lua error thrown by `v1_error` with `ScriptError`
+///
+/// Other errors are returned as is
+pub fn convert_error(err: anyhow::Error) -> anyhow::Error {
+    let Some(lua_error) = err.downcast_ref::<LuaError>() else {
+        return err;
+    };
+
+    let Some(script_error) = find_script_error(lua_error) else {
+        return err;
+    };
+
+    anyhow::Error::new(ScriptError {
+        trace: lua_error.to_string(),
+        ..script_error.clone()
+    })
+}
+
+/// Get error message thrown by the integration script
+///
+/// Lua errors contain the script location and stack traceback,
+/// so only the message itself is returned for them
+pub fn get_message(err: &anyhow::Error) -> String {
+    fn get_lua_message(err: &LuaError) -> String {
+        match err {
+            LuaError::CallbackError { cause, .. } => get_lua_message(cause),
+            LuaError::WithContext { cause, .. } => get_lua_message(cause),
+
+            LuaError::RuntimeError(message) => {
+                let message = message.lines()
+                    .next()
+                    .unwrap_or_default();
+
+                // Strip `[string "..."]:123: ` location prefix
+                match message.split_once("]:") {
+                    Some((_, message)) => match message.split_once(": ") {
+                        Some((line, message)) if line.parse::<u64>().is_ok() => message.to_string(),
+                        _ => message.to_string()
+                    },
+
+                    None => message.to_string()
+                }
+            }
+
+            _ => err.to_string()
+        }
+    }
+
+    match err.downcast_ref::<LuaError>() {
+        Some(err) => get_lua_message(err),
+        None => err.to_string()
+    }
+}
+
+/// Replace lua error with its message
+///
+/// Script errors are returned as is to keep their trace
+pub fn simplify_error(err: anyhow::Error) -> anyhow::Error {
+    if err.is::<ScriptError>() {
+        return err;
+    }
+
+    anyhow::anyhow!("{}", get_message(&err))
+}
+
+/// Get trace of the integration script error
+#[inline]
+pub fn get_trace(err: &anyhow::Error) -> Option<String> {
+    err.downcast_ref::<ScriptError>()
+        .map(ScriptError::full_trace)
+}
+
+/// Register `error` function in the lua engine
+pub fn register(lua: &Lua, standard: IntegrationStandard) -> LuaResult<()> {
+    lua.globals().set(standard.function_name("error"), lua.create_function(|_, (code, message, details): (String, String, Option<String>)| {
+        Err::<(), _>(LuaError::external(ScriptError {
+            code,
+            message,
+            details,
+            trace: String::new()
+        }))
+    })?)
+}
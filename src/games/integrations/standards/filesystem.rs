@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use mlua::prelude::*;
+
+use super::IntegrationStandard;
+use super::integrity::HashType;
+
+use crate::games::integrations::sandbox::{self, Capability};
+
+/// Functions of the filesystem API
+const FUNCTIONS: &[&str] = &[
+    "fs_exists",
+    "fs_read",
+    "fs_read_dir",
+    "fs_metadata",
+    "fs_hash_file",
+    "fs_write"
+];
+
+/// Register filesystem API in the lua engine
+///
+/// All the paths are verified to be located within
+/// the game, addons and transitions folders
+pub fn register(lua: &Lua, standard: IntegrationStandard, game_name: &str, capabilities: &[Capability]) -> LuaResult<()> {
+    let globals = lua.globals();
+
+    if !capabilities.contains(&Capability::Filesystem) {
+        for name in FUNCTIONS {
+            let name = standard.function_name(name);
+
+            globals.set(name.as_str(), sandbox::refused_function(lua, Capability::Filesystem, &name)?)?;
+        }
+
+        return Ok(());
+    }
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("fs_exists"), lua.create_function(move |_, path: String| {
+        Ok(resolve_path(&game, &path)?.exists())
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("fs_read"), lua.create_function(move |lua, path: String| {
+        let content = std::fs::read(resolve_path(&game, &path)?)
+            .map_err(LuaError::external)?;
+
+        lua.create_string(content)
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("fs_read_dir"), lua.create_function(move |lua, path: String| {
+        let entries = lua.create_table()?;
+
+        for entry in resolve_path(&game, &path)?.read_dir().map_err(LuaError::external)? {
+            let entry = entry.map_err(LuaError::external)?;
+            let file_type = entry.file_type().map_err(LuaError::external)?;
+
+            let table = lua.create_table()?;
+
+            table.set("name", entry.file_name().to_string_lossy().to_string())?;
+            table.set("path", entry.path().to_string_lossy().to_string())?;
+            table.set("type", entry_type(file_type))?;
+
+            entries.raw_push(table)?;
+        }
+
+        Ok(entries)
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("fs_metadata"), lua.create_function(move |lua, path: String| {
+        let metadata = resolve_link_path(&game, &path)?.symlink_metadata()
+            .map_err(LuaError::external)?;
+
+        let modified = metadata.modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|time| time.as_secs());
+
+        let table = lua.create_table()?;
+
+        table.set("type", entry_type(metadata.file_type()))?;
+        table.set("size", metadata.len())?;
+        table.set("modified", modified)?;
+        table.set("readonly", metadata.permissions().readonly())?;
+
+        Ok(table)
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("fs_hash_file"), lua.create_function(move |_, (path, algorithm): (String, String)| {
+        let hash = HashType::from_str(&algorithm, standard)
+            .map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        let content = std::fs::read(resolve_path(&game, &path)?)
+            .map_err(LuaError::external)?;
+
+        hash.hash(content).ok_or_else(|| {
+            LuaError::RuntimeError(format!("Unsupported hash algorithm: `{algorithm}`"))
+        })
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("fs_write"), lua.create_function(move |_, (path, data): (String, LuaString)| {
+        let path = resolve_path(&game, &path)?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(LuaError::external)?;
+        }
+
+        std::fs::write(path, data.as_bytes())
+            .map_err(LuaError::external)
+    })?)?;
+
+    Ok(())
+}
+
+/// Resolve the path if it's located within the game folders
+///
+/// Only the returned path must be used to access the filesystem
+/// since the given one can be resolved differently by the OS
+pub fn resolve_path(game_name: &str, path: &str) -> LuaResult<PathBuf> {
+    sandbox::resolve_allowed_path(game_name, path).ok_or_else(|| {
+        LuaError::RuntimeError(format!("Access to `{path}` is not allowed: it's located outside of the game folders"))
+    })
+}
+
+/// Resolve the path without following its last component
+///
+/// Used to get info about symlinks themselves rather than their targets
+fn resolve_link_path(game_name: &str, path: &str) -> LuaResult<PathBuf> {
+    let link = Path::new(path);
+
+    if let (Some(parent), Some(name)) = (link.parent(), link.file_name()) {
+        if let Some(parent) = sandbox::resolve_allowed_path(game_name, parent) {
+            return Ok(parent.join(name));
+        }
+    }
+
+    resolve_path(game_name, path)
+}
+
+fn entry_type(file_type: std::fs::FileType) -> &'static str {
+    if file_type.is_symlink() {
+        "symlink"
+    }
+
+    else if file_type.is_dir() {
+        "folder"
+    }
+
+    else {
+        "file"
+    }
+}
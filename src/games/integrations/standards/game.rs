@@ -13,7 +13,7 @@ pub struct Edition {
 impl Edition {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     name: table.get::<_, String>("name")?,
                     title: table.get::<_, String>("title")?
@@ -24,7 +24,7 @@ impl Edition {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
 
                 table.set("name", self.name.as_str())?;
@@ -46,7 +46,7 @@ pub struct Status {
 impl Status {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     allow_launch: table.get::<_, bool>("allow_launch")?,
 
@@ -62,7 +62,7 @@ impl Status {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
 
                 table.set("allow_launch", self.allow_launch)?;
@@ -88,7 +88,7 @@ pub enum StatusSeverity {
 impl StatusSeverity {
     pub fn from_str(value: impl AsRef<str>, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match value.as_ref() {
                     "critical" => Ok(Self::Critical),
                     "warning"  => Ok(Self::Warning),
@@ -102,7 +102,7 @@ impl StatusSeverity {
 
     pub fn to_str(&self, standard: IntegrationStandard) -> &str {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match self {
                     Self::Critical => "critical",
                     Self::Warning  => "warning",
@@ -123,7 +123,7 @@ pub struct LaunchOptions {
 impl LaunchOptions {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     executable: table.get::<_, String>("executable")?,
 
@@ -143,7 +143,7 @@ impl LaunchOptions {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
 
                 let options = lua.create_table()?;
@@ -2,6 +2,9 @@ use mlua::prelude::*;
 
 use super::IntegrationStandard;
 use super::diff::DiffFileDownload;
+use super::filesystem::resolve_path;
+
+use crate::games::integrations::sandbox::{self, Capability};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IntegrityInfo {
@@ -13,7 +16,7 @@ pub struct IntegrityInfo {
 impl IntegrityInfo {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     hash: HashType::from_str(table.get::<_, String>("hash")?, standard)?,
                     value: table.get::<_, String>("value")?,
@@ -25,7 +28,7 @@ impl IntegrityInfo {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
 
                 table.set("hash", self.hash.to_str(standard))?;
@@ -58,7 +61,7 @@ pub enum HashType {
 impl HashType {
     pub fn from_str(value: impl AsRef<str>, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match value.as_ref() {
                     "md5"         => Ok(Self::Md5),
                     "sha1"        => Ok(Self::Sha1),
@@ -76,7 +79,7 @@ impl HashType {
 
     pub fn to_str(&self, standard: IntegrationStandard) -> &str {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match self {
                     Self::Md5         => "md5",
                     Self::Sha1        => "sha1",
@@ -91,4 +94,78 @@ impl HashType {
             }
         }
     }
+
+    /// Hash given data with current algorithm
+    /// 
+    /// Return `None` for custom algorithms
+    pub fn hash(&self, data: impl AsRef<[u8]>) -> Option<String> {
+        let data = data.as_ref();
+
+        let hash = match self {
+            Self::Md5 => {
+                use md5::{Md5, Digest};
+
+                format!("{:x}", Md5::digest(data))
+            }
+
+            Self::Sha1 => {
+                use sha1::{Sha1, Digest};
+
+                format!("{:x}", Sha1::digest(data))
+            }
+
+            Self::Crc32 => {
+                let mut hasher = crc32fast::Hasher::new();
+
+                hasher.update(data);
+
+                hasher.finalize().to_string()
+            }
+
+            Self::Xxhash32    => format!("{:x}", xxhash_rust::xxh32::xxh32(data, 0)),
+            Self::Xxhash64    => format!("{:x}", xxhash_rust::xxh64::xxh64(data, 0)),
+            Self::Xxhash3_64  => format!("{:x}", xxhash_rust::xxh3::xxh3_64(data)),
+            Self::Xxhash3_128 => format!("{:x}", xxhash_rust::xxh3::xxh3_128(data)),
+
+            Self::Custom(_) => return None
+        };
+
+        Some(hash)
+    }
+}
+
+/// Register hashing API in the lua engine
+///
+/// Files can be hashed only with `filesystem` capability
+pub fn register(lua: &Lua, standard: IntegrationStandard, game_name: &str, capabilities: &[Capability]) -> LuaResult<()> {
+    let name = standard.function_name("hash");
+
+    let game = game_name.to_string();
+    let allow_files = capabilities.contains(&Capability::Filesystem);
+
+    lua.globals().set(standard.function_name("hash"), lua.create_function(move |_, (algorithm, input): (String, LuaValue)| {
+        let hash = HashType::from_str(&algorithm, standard)
+            .map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        let data = match input {
+            LuaValue::String(data) => data.as_bytes().to_vec(),
+
+            LuaValue::Table(table) if allow_files => {
+                let path = table.get::<_, String>("path")?;
+
+                std::fs::read(resolve_path(&game, &path)?)
+                    .map_err(LuaError::external)?
+            }
+
+            LuaValue::Table(_) => return Err(sandbox::refused_error(Capability::Filesystem, &name)),
+
+            _ => return Err(LuaError::RuntimeError(format!("`{name}` expects data string or `{{ path }}` table as an input")))
+        };
+
+        hash.hash(data).ok_or_else(|| {
+            LuaError::RuntimeError(format!("Unsupported hash algorithm: `{algorithm}`"))
+        })
+    })?)?;
+
+    Ok(())
 }
@@ -0,0 +1,83 @@
+use mlua::prelude::*;
+
+use crate::config;
+use crate::config::games::wine::wine_lang::WineLang;
+
+use crate::APP_VERSION;
+
+use super::IntegrationStandard;
+
+/// Launcher info available to the integration scripts
+///
+/// Fields of this struct are a part of the integration standard,
+/// so they can be added but never renamed or removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LauncherInfo {
+    pub launcher_version: String,
+
+    /// Launcher language in `en-us` format
+    pub language: String,
+
+    /// Wine language code, e.g. `en_US.UTF-8`,
+    /// or `None` if system language is used
+    pub wine_language: Option<String>,
+
+    pub wine_build: String,
+    pub wine_version: String,
+
+    pub dxvk_build: String,
+    pub dxvk_version: String,
+
+    pub offline: bool
+}
+
+impl LauncherInfo {
+    pub fn from_config(config: &config::Config) -> Self {
+        let wine_language = match config.games.wine.language {
+            WineLang::System => None,
+            language => Some(language.code().to_string())
+        };
+
+        Self {
+            launcher_version: APP_VERSION.to_string(),
+            language: config.general.language.clone(),
+            wine_language,
+            wine_build: config.components.wine.build.clone(),
+            wine_version: config.components.wine.version.clone(),
+            dxvk_build: config.components.dxvk.build.clone(),
+            dxvk_version: config.components.dxvk.version.clone(),
+            offline: crate::offline::is_offline()
+        }
+    }
+
+    pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                let table = lua.create_table()?;
+
+                table.set("launcher_version", self.launcher_version.as_str())?;
+                table.set("language", self.language.as_str())?;
+                table.set("wine_language", self.wine_language.as_deref())?;
+                table.set("wine_build", self.wine_build.as_str())?;
+                table.set("wine_version", self.wine_version.as_str())?;
+                table.set("dxvk_build", self.dxvk_build.as_str())?;
+                table.set("dxvk_version", self.dxvk_version.as_str())?;
+                table.set("offline", self.offline)?;
+
+                Ok(table)
+            }
+        }
+    }
+}
+
+/// Register `launcher_info` function in the lua engine
+///
+/// Info is read from the config on every call,
+/// so scripts always get the current values
+pub fn register(lua: &Lua, standard: IntegrationStandard) -> LuaResult<()> {
+    lua.globals().set(standard.function_name("launcher_info"), lua.create_function(move |lua, ()| {
+        LauncherInfo::from_config(&config::get())
+            .to_table(lua, standard)
+            .map_err(|err| LuaError::RuntimeError(err.to_string()))
+    })?)
+}
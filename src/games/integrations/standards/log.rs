@@ -0,0 +1,55 @@
+use mlua::prelude::*;
+
+use crate::config;
+use crate::config::games::integrations::LogLevel;
+
+use super::{IntegrationStandard, CallContext};
+
+/// Emit tracing event of the integration script
+fn log(lua: &Lua, game_name: &str, level: LogLevel, message: &str) {
+    let edition = lua.app_data_ref::<CallContext>()
+        .and_then(|context| context.edition.clone())
+        .unwrap_or_default();
+
+    match level {
+        LogLevel::Trace => tracing::trace!(target: "integration", game = %game_name, edition = %edition, "{message}"),
+        LogLevel::Debug => tracing::debug!(target: "integration", game = %game_name, edition = %edition, "{message}"),
+        LogLevel::Info  => tracing::info!(target: "integration", game = %game_name, edition = %edition, "{message}"),
+        LogLevel::Warn  => tracing::warn!(target: "integration", game = %game_name, edition = %edition, "{message}"),
+        LogLevel::Error => tracing::error!(target: "integration", game = %game_name, edition = %edition, "{message}"),
+
+        LogLevel::Off => ()
+    }
+}
+
+/// Register `log_*` functions in the lua engine
+///
+/// Messages below the game's log level from the config are ignored
+pub fn register(lua: &Lua, standard: IntegrationStandard, game_name: &str) -> LuaResult<()> {
+    let min_level = config::get().games.integrations.log_levels
+        .get(game_name)
+        .copied()
+        .unwrap_or_default();
+
+    let functions = [
+        ("log_trace", LogLevel::Trace),
+        ("log_debug", LogLevel::Debug),
+        ("log_info",  LogLevel::Info),
+        ("log_warn",  LogLevel::Warn),
+        ("log_error", LogLevel::Error)
+    ];
+
+    for (name, level) in functions {
+        let game_name = game_name.to_string();
+
+        lua.globals().set(standard.function_name(name), lua.create_function(move |lua, message: String| {
+            if level >= min_level {
+                log(lua, &game_name, level, &message);
+            }
+
+            Ok(())
+        })?)?;
+    }
+
+    Ok(())
+}
@@ -4,6 +4,18 @@ pub mod diff;
 pub mod download;
 pub mod integrity;
 pub mod network;
+pub mod filesystem;
+pub mod archive;
+pub mod error;
+pub mod log;
+pub mod settings;
+pub mod news;
+pub mod storage;
+pub mod ui;
+pub mod launcher;
+pub mod prefix;
+pub mod compatibility;
+pub mod scheduler;
 
 pub mod prelude {
     pub use super::game::{
@@ -17,11 +29,43 @@ pub mod prelude {
     pub use super::download::*;
     pub use super::integrity::*;
     pub use super::network::*;
+    pub use super::settings::*;
+    pub use super::news::*;
+    pub use super::prefix::*;
+    pub use super::compatibility::*;
 
     pub use super::IntegrationStandard;
 }
 
+/// Context of the current integration script call
+///
+/// Stored in the lua app data while the call is processed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallContext {
+    /// Game edition the call is made for
+    pub edition: Option<String>,
+
+    /// Dialogs can be shown to the user during the call
+    ///
+    /// False when the call is made from the GTK main thread
+    pub interactive: bool
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntegrationStandard {
-    V1
+    V1,
+
+    /// Same as V1, but functions are executed as coroutines
+    /// and network requests don't block each other
+    V2
+}
+
+impl IntegrationStandard {
+    /// Get integration script function name according to the standard
+    pub fn function_name(&self, name: &str) -> String {
+        match self {
+            Self::V1 => format!("v1_{name}"),
+            Self::V2 => format!("v2_{name}")
+        }
+    }
 }
@@ -1,10 +1,18 @@
 use std::collections::HashMap;
 
+use serde_json::Value as Json;
+
 use mlua::prelude::*;
 
+use anime_game_core::network::minreq;
+
 pub use anime_game_core::network::minreq::Method as RequestMethod;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use crate::config::games::integrations::FixturesMode;
+use crate::offline;
+use crate::games::integrations::{fixtures, http_cache};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct RequestOptions {
     /// Request method
     pub method: Option<RequestMethod>,
@@ -16,7 +24,10 @@ pub struct RequestOptions {
     pub body: Option<Vec<u8>>,
 
     /// Request timeout, in seconds
-    pub timeout: Option<u64>
+    pub timeout: Option<u64>,
+
+    /// Use network cache for the request
+    pub cache: Option<bool>
 }
 
 impl<'lua> FromLua<'lua> for RequestOptions {
@@ -77,7 +88,262 @@ impl<'lua> FromLua<'lua> for RequestOptions {
 
             timeout: table.contains_key("timeout")?
                 .then(|| table.get("timeout"))
+                .transpose()?,
+
+            cache: table.contains_key("cache")?
+                .then(|| table.get("cache"))
                 .transpose()?
         })
     }
 }
+
+/// Network response returned to the integration scripts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub url: String,
+    pub status: i32,
+    pub status_text: String,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>
+}
+
+impl From<minreq::Response> for Response {
+    fn from(response: minreq::Response) -> Self {
+        Self {
+            body: response.as_bytes().to_vec(),
+            url: response.url,
+            status: response.status_code,
+            status_text: response.reason_phrase,
+            headers: response.headers
+        }
+    }
+}
+
+/// Perform network request with given options
+/// 
+/// Requests are recorded to or replayed from
+/// the network fixtures if they're enabled
+pub fn fetch(uri: impl AsRef<str>, options: Option<RequestOptions>) -> anyhow::Result<Response> {
+    let fixtures = fixtures::get_settings();
+
+    if fixtures.mode == FixturesMode::Replay {
+        return fixtures::replay(&fixtures.path, uri.as_ref(), options.as_ref());
+    }
+
+    let response = fetch_cached(uri.as_ref(), options.clone())?;
+
+    if fixtures.mode == FixturesMode::Record {
+        fixtures::record(&fixtures.path, uri.as_ref(), options.as_ref(), &response)?;
+    }
+
+    Ok(response)
+}
+
+/// Send network request using the network cache
+/// 
+/// Only GET requests without user credentials are cached. Cached
+/// response is returned if it's fresh, or if the server is not available
+fn fetch_cached(uri: &str, options: Option<RequestOptions>) -> anyhow::Result<Response> {
+    let method = options.as_ref()
+        .and_then(|options| options.method.clone())
+        .unwrap_or(RequestMethod::Get);
+
+    let cache = options.as_ref()
+        .and_then(|options| options.cache);
+
+    let headers = options.as_ref()
+        .and_then(|options| options.headers.as_ref());
+
+    if method != RequestMethod::Get || cache == Some(false) || http_cache::is_private_request(headers) {
+        return send(uri, options);
+    }
+
+    let key = http_cache::get_key(uri, headers);
+    let explicit = cache == Some(true);
+
+    let Some(entry) = http_cache::get(&key) else {
+        let response = send(uri, options)?;
+
+        if let Err(err) = http_cache::store(&key, uri, &response, explicit) {
+            tracing::warn!("Failed to cache {uri} response: {err}");
+        }
+
+        return Ok(response);
+    };
+
+    // Don't wait for network timeouts in offline mode
+    if entry.is_fresh() || offline::is_offline() {
+        return entry.to_response();
+    }
+
+    // Validate cached response
+    let mut options = options.unwrap_or_default();
+    let headers = options.headers.get_or_insert_with(HashMap::new);
+
+    if let Some(etag) = entry.etag() {
+        headers.insert(String::from("If-None-Match"), etag.to_string());
+    }
+
+    if let Some(last_modified) = entry.last_modified() {
+        headers.insert(String::from("If-Modified-Since"), last_modified.to_string());
+    }
+
+    match send(uri, Some(options)) {
+        // Not Modified
+        Ok(response) if response.status == 304 => {
+            match http_cache::revalidate(entry.clone(), &response) {
+                Ok(entry) => entry.to_response(),
+                Err(err) => {
+                    tracing::warn!("Failed to update {uri} cached response: {err}");
+
+                    entry.to_response()
+                }
+            }
+        }
+
+        Ok(response) => {
+            if let Err(err) = http_cache::store(&key, uri, &response, explicit) {
+                tracing::warn!("Failed to cache {uri} response: {err}");
+            }
+
+            Ok(response)
+        }
+
+        // Show last known state if the server is not available
+        Err(err) => {
+            tracing::warn!("Failed to fetch {uri}, using cached response: {err}");
+
+            entry.to_response()
+        }
+    }
+}
+
+/// Send network request without using fixtures and cache
+fn send(uri: &str, options: Option<RequestOptions>) -> anyhow::Result<Response> {
+    let (method, headers, body, timeout) = match options {
+        Some(options) => {
+            let method = options.method.unwrap_or(RequestMethod::Get);
+
+            (method, options.headers, options.body, options.timeout)
+        }
+
+        None => (RequestMethod::Get, None, None, None)
+    };
+
+    let mut request = minreq::Request::new(method, uri);
+
+    if let Some(headers) = headers {
+        for (key, value) in headers {
+            request = request.with_header(key, value);
+        }
+    }
+
+    if let Some(body) = body {
+        request = request.with_body(body);
+    }
+
+    if let Some(timeout) = timeout {
+        request = request.with_timeout(timeout);
+    }
+
+    Ok(Response::from(request.send()?))
+}
+
+/// Convert network response to the `Response` lua table
+pub fn response_to_table(lua: &Lua, response: Response) -> LuaResult<LuaTable> {
+    let result = lua.create_table()?;
+    let headers = lua.create_table()?;
+
+    for (key, value) in &response.headers {
+        headers.set(key.as_str(), value.as_str())?;
+    }
+
+    result.set("url", response.url.as_str())?;
+    result.set("status", response.status)?;
+    result.set("statusText", response.status_text.as_str())?;
+    result.set("ok", (200..300).contains(&response.status))?;
+    result.set("headers", headers)?;
+    result.set("body", lua.create_string(&response.body)?)?;
+
+    result.set("json", lua.create_function(move |lua, _: ()| {
+        serde_json::from_slice::<Json>(&response.body)
+            .map(|value| lua.to_value(&value))
+            .map_err(LuaError::external)
+    })?)?;
+
+    Ok(result)
+}
+
+/// Network request performed in background thread
+/// 
+/// Returned by the `v2_network_fetch_async` function
+#[derive(Debug)]
+pub struct PendingRequest {
+    receiver: flume::Receiver<anyhow::Result<Response>>,
+    response: Option<anyhow::Result<Response>>,
+    table: Option<LuaRegistryKey>
+}
+
+impl PendingRequest {
+    /// Start network request in background thread
+    /// 
+    /// `notifier` will receive a message when the request is finished
+    pub fn start(uri: String, options: Option<RequestOptions>, notifier: flume::Sender<()>) -> Self {
+        let (sender, receiver) = flume::bounded(1);
+
+        std::thread::spawn(move || {
+            let _ = sender.send(fetch(uri, options));
+            let _ = notifier.send(());
+        });
+
+        Self {
+            receiver,
+            response: None,
+            table: None
+        }
+    }
+
+    /// Check if the request is finished without blocking current thread
+    pub fn is_finished(&mut self) -> bool {
+        if self.table.is_some() || self.response.is_some() {
+            return true;
+        }
+
+        match self.receiver.try_recv() {
+            Ok(response) => {
+                self.response = Some(response);
+
+                true
+            }
+
+            Err(_) => false
+        }
+    }
+
+    /// Wait for the request's response and convert it to the `Response` lua table
+    pub fn wait<'lua>(&mut self, lua: &'lua Lua) -> LuaResult<LuaTable<'lua>> {
+        if let Some(table) = &self.table {
+            return lua.registry_value(table);
+        }
+
+        let response = match self.response.take() {
+            Some(response) => response,
+            None => self.receiver.recv().map_err(LuaError::external)?
+        };
+
+        let response = response.map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        let table = response_to_table(lua, response)?;
+
+        self.table = Some(lua.create_registry_value(table.clone())?);
+
+        Ok(table)
+    }
+}
+
+impl LuaUserData for PendingRequest {
+    fn add_methods<'lua, M: LuaUserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method_mut("is_finished", |_, request, ()| Ok(request.is_finished()));
+        methods.add_method_mut("wait", |lua, request, ()| request.wait(lua));
+    }
+}
@@ -0,0 +1,50 @@
+use mlua::prelude::*;
+
+use super::IntegrationStandard;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NewsItem {
+    pub title: String,
+    pub summary: Option<String>,
+
+    /// Publication date in any human readable format
+    pub date: Option<String>,
+
+    /// URI of the news picture
+    pub image: Option<String>,
+
+    /// URI opened when the news is clicked
+    pub link: Option<String>
+}
+
+impl NewsItem {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                Ok(Self {
+                    title: table.get::<_, String>("title")?,
+                    summary: table.get::<_, Option<String>>("summary")?,
+                    date: table.get::<_, Option<String>>("date")?,
+                    image: table.get::<_, Option<String>>("image")?,
+                    link: table.get::<_, Option<String>>("link")?
+                })
+            }
+        }
+    }
+
+    pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                let table = lua.create_table()?;
+
+                table.set("title", self.title.as_str())?;
+                table.set("summary", self.summary.as_deref())?;
+                table.set("date", self.date.as_deref())?;
+                table.set("image", self.image.as_deref())?;
+                table.set("link", self.link.as_deref())?;
+
+                Ok(table)
+            }
+        }
+    }
+}
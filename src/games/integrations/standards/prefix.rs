@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use mlua::prelude::*;
+
+use wincompatlib::prelude::Font;
+
+use super::IntegrationStandard;
+
+/// Find core font by its lowercase name, e.g. `arial`
+pub fn get_font(name: &str) -> Option<Font> {
+    Font::iterator()
+        .into_iter()
+        .find(|font| format!("{font:?}").to_ascii_lowercase() == name)
+}
+
+/// Check that winetricks verb contains only `[a-z0-9_]` characters
+///
+/// Verbs are given by the integration scripts, so anything else
+/// (e.g. `-`, `/` or `.`) could be treated by winetricks as an option or a file
+pub fn is_valid_verb(verb: &str) -> bool {
+    !verb.is_empty() && verb.chars().all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_')
+}
+
+/// Wine prefix components required by the game edition
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixRequirements {
+    /// Names of the core fonts, e.g. `arial`
+    pub fonts: Vec<String>,
+
+    /// DLL name to the override mode, e.g. `native,builtin`
+    pub dll_overrides: HashMap<String, String>,
+
+    /// Winetricks verbs, e.g. `vcrun2019`
+    pub verbs: Vec<String>
+}
+
+impl PrefixRequirements {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                let fonts = match table.get::<_, Option<LuaTable>>("fonts")? {
+                    Some(fonts) => fonts.sequence_values::<String>()
+                        .map(|font| font.map(|font| font.to_ascii_lowercase()))
+                        .collect::<Result<Vec<_>, _>>()?,
+
+                    None => vec![]
+                };
+
+                let dll_overrides = match table.get::<_, Option<LuaTable>>("dll_overrides")? {
+                    Some(overrides) => overrides.pairs::<String, String>()
+                        .collect::<Result<HashMap<_, _>, _>>()?,
+
+                    None => HashMap::new()
+                };
+
+                let verbs = match table.get::<_, Option<LuaTable>>("verbs")? {
+                    Some(verbs) => verbs.sequence_values::<String>()
+                        .collect::<Result<Vec<_>, _>>()?,
+
+                    None => vec![]
+                };
+
+                if let Some(font) = fonts.iter().find(|font| get_font(font).is_none()) {
+                    anyhow::bail!("Unknown core font: {font}");
+                }
+
+                if let Some(verb) = verbs.iter().find(|verb| !is_valid_verb(verb)) {
+                    anyhow::bail!("Invalid winetricks verb: {verb}");
+                }
+
+                Ok(Self {
+                    fonts,
+                    dll_overrides,
+                    verbs
+                })
+            }
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty() && self.dll_overrides.is_empty() && self.verbs.is_empty()
+    }
+
+    /// Add requirements of another game edition
+    ///
+    /// If editions require different modes of the same DLL override,
+    /// the current one is kept and a warning is logged
+    pub fn merge(&mut self, other: PrefixRequirements) {
+        for font in other.fonts {
+            if !self.fonts.contains(&font) {
+                self.fonts.push(font);
+            }
+        }
+
+        for verb in other.verbs {
+            if !self.verbs.contains(&verb) {
+                self.verbs.push(verb);
+            }
+        }
+
+        for (name, mode) in other.dll_overrides {
+            match self.dll_overrides.get(&name) {
+                Some(current_mode) if current_mode != &mode => {
+                    tracing::warn!(%name, %current_mode, %mode, "Conflicting DLL override modes required, keeping the current one");
+                }
+
+                Some(_) => (),
+
+                None => {
+                    self.dll_overrides.insert(name, mode);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_fonts() {
+        assert!(get_font("arial").is_some());
+        assert!(get_font("webdings").is_some());
+
+        assert!(get_font("Arial").is_none());
+        assert!(get_font("comic_sans").is_none());
+    }
+
+    #[test]
+    fn winetricks_verbs() {
+        assert!(is_valid_verb("vcrun2019"));
+        assert!(is_valid_verb("d3dx9_43"));
+
+        assert!(!is_valid_verb(""));
+        assert!(!is_valid_verb("-q"));
+        assert!(!is_valid_verb("../verb"));
+        assert!(!is_valid_verb("VCRUN2019"));
+    }
+
+    #[test]
+    fn invalid_requirements_are_refused() -> anyhow::Result<()> {
+        let lua = Lua::new();
+
+        let table = lua.load(r#"{ fonts = { "Arial" }, verbs = { "vcrun2019" } }"#).eval::<LuaTable>()?;
+        let requirements = PrefixRequirements::from_table(table, IntegrationStandard::V1)?;
+
+        assert_eq!(requirements.fonts, vec![String::from("arial")]);
+
+        let table = lua.load(r#"{ fonts = { "comic_sans" } }"#).eval::<LuaTable>()?;
+
+        assert!(PrefixRequirements::from_table(table, IntegrationStandard::V1).is_err());
+
+        let table = lua.load(r#"{ verbs = { "--force" } }"#).eval::<LuaTable>()?;
+
+        assert!(PrefixRequirements::from_table(table, IntegrationStandard::V2).is_err());
+
+        Ok(())
+    }
+}
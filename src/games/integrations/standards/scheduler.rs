@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use mlua::prelude::*;
+
+use super::network::PendingRequest;
+
+/// Lua code loaded before v2 integration scripts
+pub const V2_PRELUDE: &str = r#"
+    function v2_network_fetch(uri, options)
+        local request = v2_network_fetch_async(uri, options)
+        local thread, is_main = coroutine.running()
+
+        -- Let other coroutines work while the request is processing
+        if thread ~= nil and not is_main then
+            while not request:is_finished() do
+                coroutine.yield(request)
+            end
+        end
+
+        return request:wait()
+    end
+"#;
+
+/// Receiver notified every time any network request is finished
+///
+/// Stored in the lua engine's app data
+#[derive(Debug)]
+pub struct RequestsNotifier(pub flume::Receiver<()>);
+
+/// Resume given coroutines until all of them are finished
+///
+/// Coroutines which yielded pending network requests are not resumed
+/// until their requests are finished. When all the coroutines are waiting
+/// for their requests current thread is blocked
+pub fn run_threads<'lua>(lua: &'lua Lua, threads: Vec<(LuaThread<'lua>, LuaMultiValue<'lua>)>) -> LuaResult<Vec<LuaMultiValue<'lua>>> {
+    let (threads, args): (Vec<_>, Vec<_>) = threads.into_iter().unzip();
+
+    let mut args = args.into_iter().map(Some).collect::<Vec<_>>();
+    let mut results = threads.iter().map(|_| None).collect::<Vec<Option<LuaMultiValue>>>();
+    let mut waiting = vec![None::<LuaAnyUserData>; threads.len()];
+
+    while results.iter().any(Option::is_none) {
+        let mut progressed = false;
+
+        for (i, thread) in threads.iter().enumerate() {
+            if results[i].is_some() {
+                continue;
+            }
+
+            if let Some(request) = &waiting[i] {
+                if !request.borrow_mut::<PendingRequest>()?.is_finished() {
+                    continue;
+                }
+            }
+
+            waiting[i] = None;
+            progressed = true;
+
+            let output = thread.resume::<_, LuaMultiValue>(args[i].take().unwrap_or_default())?;
+
+            if thread.status() == LuaThreadStatus::Resumable {
+                waiting[i] = match output.into_iter().next() {
+                    Some(LuaValue::UserData(request)) if request.is::<PendingRequest>() => Some(request),
+
+                    _ => None
+                };
+            }
+
+            else {
+                results[i] = Some(output);
+            }
+        }
+
+        // Block until some request is finished
+        if !progressed {
+            if let Some(notifier) = lua.app_data_ref::<RequestsNotifier>() {
+                let _ = notifier.0.recv_timeout(Duration::from_millis(100));
+            }
+        }
+    }
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// Execute lua function as a coroutine
+pub fn run<'lua, A, R>(lua: &'lua Lua, function: LuaFunction<'lua>, args: A) -> LuaResult<R>
+where
+    A: IntoLuaMulti<'lua>,
+    R: FromLuaMulti<'lua>
+{
+    let thread = lua.create_thread(function)?;
+
+    let mut results = run_threads(lua, vec![(thread, args.into_lua_multi(lua)?)])?;
+
+    R::from_lua_multi(results.remove(0), lua)
+}
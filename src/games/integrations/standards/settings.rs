@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use serde_json::Value as Json;
+
+use mlua::prelude::*;
+
+use super::IntegrationStandard;
+
+/// Game setting defined by the integration script
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsField {
+    pub name: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub kind: SettingsFieldKind
+}
+
+impl SettingsField {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                Ok(Self {
+                    name: table.get::<_, String>("name")?,
+                    title: table.get::<_, String>("title")?,
+                    description: table.get::<_, Option<String>>("description")?,
+
+                    kind: SettingsFieldKind::from_table(&table, standard)?
+                })
+            }
+        }
+    }
+
+    /// Get default value of the setting
+    pub fn default_value(&self) -> Json {
+        match &self.kind {
+            SettingsFieldKind::Bool { default } => Json::from(*default),
+            SettingsFieldKind::Enum { default, .. } => Json::from(default.as_str()),
+            SettingsFieldKind::String { default } => Json::from(default.as_str()),
+            SettingsFieldKind::Number { default, .. } => Json::from(*default)
+        }
+    }
+
+    /// Get stored setting value if it matches the field type,
+    /// otherwise the default value is returned
+    pub fn get_value(&self, value: Option<&Json>) -> Json {
+        let Some(value) = value else {
+            return self.default_value();
+        };
+
+        let valid = match &self.kind {
+            SettingsFieldKind::Bool { .. } => value.is_boolean(),
+
+            SettingsFieldKind::Enum { values, .. } => value.as_str()
+                .map(|value| values.iter().any(|variant| variant.name == value))
+                .unwrap_or(false),
+
+            SettingsFieldKind::String { .. } => value.is_string(),
+
+            SettingsFieldKind::Number { min, max, .. } => value.as_f64()
+                .map(|value| min.map(|min| value >= min).unwrap_or(true) && max.map(|max| value <= max).unwrap_or(true))
+                .unwrap_or(false)
+        };
+
+        if valid {
+            value.clone()
+        }
+
+        else {
+            self.default_value()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingsFieldKind {
+    Bool {
+        default: bool
+    },
+
+    Enum {
+        values: Vec<SettingsEnumValue>,
+        default: String
+    },
+
+    String {
+        default: String
+    },
+
+    Number {
+        default: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+        step: Option<f64>
+    }
+}
+
+impl SettingsFieldKind {
+    pub fn from_table(table: &LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                let kind = table.get::<_, String>("type")?;
+
+                match kind.as_str() {
+                    "bool" => Ok(Self::Bool {
+                        default: table.get::<_, Option<bool>>("default")?
+                            .unwrap_or_default()
+                    }),
+
+                    "enum" => {
+                        let values = table.get::<_, LuaTable>("values")?
+                            .sequence_values::<LuaTable>()
+                            .flatten()
+                            .map(|value| SettingsEnumValue::from_table(value, standard))
+                            .collect::<anyhow::Result<Vec<_>>>()?;
+
+                        let default = match table.get::<_, Option<String>>("default")? {
+                            Some(default) => default,
+
+                            None => match values.first() {
+                                Some(value) => value.name.clone(),
+                                None => anyhow::bail!("Enum setting must have at least one value")
+                            }
+                        };
+
+                        Ok(Self::Enum {
+                            values,
+                            default
+                        })
+                    }
+
+                    "string" => Ok(Self::String {
+                        default: table.get::<_, Option<String>>("default")?
+                            .unwrap_or_default()
+                    }),
+
+                    "number" => Ok(Self::Number {
+                        default: table.get::<_, Option<f64>>("default")?
+                            .unwrap_or_default(),
+
+                        min: table.get::<_, Option<f64>>("min")?,
+                        max: table.get::<_, Option<f64>>("max")?,
+                        step: table.get::<_, Option<f64>>("step")?
+                    }),
+
+                    _ => anyhow::bail!("Wrong v1 setting type: '{kind}'")
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SettingsEnumValue {
+    pub name: String,
+    pub title: String
+}
+
+impl SettingsEnumValue {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
+                Ok(Self {
+                    name: table.get::<_, String>("name")?,
+                    title: table.get::<_, String>("title")?
+                })
+            }
+        }
+    }
+}
+
+/// Create table of the settings values passed to the integration script
+///
+/// Values which are not stored or don't match the schema are replaced by defaults
+pub fn values_to_table<'a>(lua: &'a Lua, schema: &[SettingsField], values: &HashMap<String, Json>, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+    match standard {
+        IntegrationStandard::V1 | IntegrationStandard::V2 => {
+            let table = lua.create_table()?;
+
+            for field in schema {
+                let value = match field.get_value(values.get(&field.name)) {
+                    Json::Bool(value) => LuaValue::Boolean(value),
+                    Json::Number(value) => LuaValue::Number(value.as_f64().unwrap_or_default()),
+                    Json::String(value) => LuaValue::String(lua.create_string(value)?),
+
+                    _ => LuaValue::Nil
+                };
+
+                table.set(field.name.as_str(), value)?;
+            }
+
+            Ok(table)
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value as Json;
+
+use mlua::prelude::*;
+
+use super::{IntegrationStandard, CallContext};
+
+use crate::config;
+use crate::LAUNCHER_FOLDER;
+
+/// Name of the storage file stored next to the `launcher_metadata.json`
+const STORAGE_FILE_NAME: &str = "integration_storage.json";
+
+/// Check that the edition name can be used as a path component
+fn is_valid_edition(edition: &str) -> bool {
+    !edition.is_empty() && edition != "." && edition != ".." && !edition.contains(['/', '\\'])
+}
+
+/// Get path to the storage file of the game edition
+///
+/// Storage is only available for calls made for a specific game edition
+fn get_storage_path(lua: &Lua, game_name: &str) -> LuaResult<PathBuf> {
+    let edition = lua.app_data_ref::<CallContext>()
+        .and_then(|context| context.edition.clone());
+
+    let Some(edition) = edition else {
+        return Err(LuaError::RuntimeError(String::from("storage is only available in functions called for a game edition")));
+    };
+
+    if !is_valid_edition(&edition) {
+        return Err(LuaError::RuntimeError(format!("invalid game edition name: {edition}")));
+    }
+
+    Ok(LAUNCHER_FOLDER
+        .join("games")
+        .join(game_name)
+        .join(edition)
+        .join(STORAGE_FILE_NAME))
+}
+
+/// Read storage file
+///
+/// Missing storage is treated as empty one. Corrupted storage
+/// is moved aside so it's not overwritten by the next write
+fn read(path: &Path) -> anyhow::Result<serde_json::Map<String, Json>> {
+    let storage = match std::fs::read(path) {
+        Ok(storage) => storage,
+
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(serde_json::Map::new()),
+        Err(err) => return Err(err.into())
+    };
+
+    match serde_json::from_slice::<Json>(&storage) {
+        Ok(Json::Object(storage)) => Ok(storage),
+
+        result => {
+            let corrupted_path = path.with_extension("json.corrupted");
+
+            tracing::error!(?path, ?corrupted_path, "Integration storage is corrupted: {result:?}");
+
+            std::fs::rename(path, corrupted_path)?;
+
+            Ok(serde_json::Map::new())
+        }
+    }
+}
+
+/// Write storage file if it fits the size limit from the config
+///
+/// Storage is written to the temp file first and then renamed,
+/// so the storage file is never partially written
+fn write(path: &Path, storage: &serde_json::Map<String, Json>) -> anyhow::Result<()> {
+    let storage = serde_json::to_vec(storage)?;
+
+    let limit = config::get().games.integrations.storage_limit * 1024;
+
+    if storage.len() as u64 > limit {
+        anyhow::bail!("storage size limit exceeded: {} bytes out of {limit} allowed", storage.len());
+    }
+
+    if let Some(folder) = path.parent() {
+        std::fs::create_dir_all(folder)?;
+    }
+
+    let temp_path = path.with_extension("json.tmp");
+
+    let mut file = std::fs::File::create(&temp_path)?;
+
+    file.write_all(&storage)?;
+    file.sync_all()?;
+
+    std::fs::rename(temp_path, path)?;
+
+    Ok(())
+}
+
+/// Register `storage_*` functions in the lua engine
+///
+/// Each game edition has its own storage file
+/// in the launcher's games folder
+pub fn register(lua: &Lua, standard: IntegrationStandard, game_name: &str) -> LuaResult<()> {
+    let globals = lua.globals();
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("storage_get"), lua.create_function(move |lua, key: String| {
+        let storage = read(&get_storage_path(lua, &game)?)
+            .map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        match storage.get(&key) {
+            Some(value) => lua.to_value(value),
+            None => Ok(LuaNil)
+        }
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("storage_set"), lua.create_function(move |lua, (key, value): (String, LuaValue)| {
+        let path = get_storage_path(lua, &game)?;
+
+        let mut storage = read(&path)
+            .map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        // Setting nil value is the same as deleting it
+        if value.is_nil() {
+            storage.remove(&key);
+        }
+
+        else {
+            storage.insert(key, lua.from_value::<Json>(value)?);
+        }
+
+        write(&path, &storage).map_err(|err| LuaError::RuntimeError(err.to_string()))
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("storage_delete"), lua.create_function(move |lua, key: String| {
+        let path = get_storage_path(lua, &game)?;
+
+        let mut storage = read(&path)
+            .map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+
+        if storage.remove(&key).is_some() {
+            write(&path, &storage).map_err(|err| LuaError::RuntimeError(err.to_string()))?;
+        }
+
+        Ok(())
+    })?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_storage(name: &str, content: Option<&str>) -> PathBuf {
+        let folder = std::env::temp_dir()
+            .join(format!("anime-games-launcher-storage-{}-{name}", std::process::id()));
+
+        let _ = std::fs::remove_dir_all(&folder);
+
+        std::fs::create_dir_all(&folder).unwrap();
+
+        let path = folder.join(STORAGE_FILE_NAME);
+
+        if let Some(content) = content {
+            std::fs::write(&path, content).unwrap();
+        }
+
+        path
+    }
+
+    #[test]
+    fn edition_names() {
+        assert!(is_valid_edition("global"));
+        assert!(is_valid_edition("china.v2"));
+
+        assert!(!is_valid_edition(""));
+        assert!(!is_valid_edition("."));
+        assert!(!is_valid_edition(".."));
+        assert!(!is_valid_edition("../global"));
+        assert!(!is_valid_edition("global\\..\\china"));
+    }
+
+    #[test]
+    fn read_storage() -> anyhow::Result<()> {
+        let path = temp_storage("missing", None);
+
+        assert!(read(&path)?.is_empty());
+        assert!(!path.exists());
+
+        let path = temp_storage("valid", Some(r#"{"key":"value"}"#));
+
+        assert_eq!(read(&path)?.get("key"), Some(&Json::from("value")));
+
+        std::fs::remove_dir_all(path.parent().unwrap())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn corrupted_storage_is_moved_aside() -> anyhow::Result<()> {
+        for (name, content) in [("broken", r#"{"key":"#), ("array", r#"["value"]"#)] {
+            let path = temp_storage(name, Some(content));
+
+            assert!(read(&path)?.is_empty());
+            assert!(!path.exists());
+
+            let corrupted_path = path.with_extension("json.corrupted");
+
+            assert_eq!(std::fs::read_to_string(&corrupted_path)?, content);
+
+            std::fs::remove_dir_all(path.parent().unwrap())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use adw::prelude::*;
+
+use mlua::prelude::*;
+
+use crate::tr;
+
+use super::{IntegrationStandard, CallContext};
+
+use crate::games::integrations::limits;
+
+/// Check if dialogs can be shown to the user
+///
+/// Launcher works in headless mode when there's no window
+/// to show dialogs on (e.g. `integration check` command),
+/// or when the call blocks the GTK main thread
+fn is_interactive(lua: &Lua) -> bool {
+    let has_window = unsafe {
+        crate::ui::windows::main::WINDOW.is_some() || crate::ui::windows::loading::WINDOW.is_some()
+    };
+
+    let interactive = lua.app_data_ref::<CallContext>()
+        .map(|context| context.interactive)
+        .unwrap_or(false);
+
+    has_window && interactive
+}
+
+/// Show message dialog in the GTK main context and wait for the user's response
+///
+/// Returns id of the chosen response. Closing the dialog
+/// is the same as choosing the default response
+fn show_dialog(lua: &Lua, title: String, message: String, responses: Vec<(String, String)>, default: String) -> LuaResult<String> {
+    let (sender, receiver) = flume::bounded(1);
+
+    gtk::glib::MainContext::default().spawn(async move {
+        let window = unsafe {
+            match crate::ui::windows::main::WINDOW.as_ref() {
+                Some(window) => Some(window.clone().upcast::<gtk::Window>()),
+                None => crate::ui::windows::loading::WINDOW.as_ref()
+                    .map(|window| window.clone().upcast::<gtk::Window>())
+            }
+        };
+
+        let dialog = adw::MessageDialog::new(window.as_ref(), Some(&title), Some(&message));
+
+        for (id, label) in &responses {
+            dialog.add_response(id, label);
+        }
+
+        dialog.set_default_response(Some(&default));
+        dialog.set_close_response(&default);
+
+        dialog.connect_response(None, move |_, id| {
+            let _ = sender.send(id.to_string());
+        });
+
+        dialog.present();
+    });
+
+    // Don't count the user's thinking time towards the call limits
+    limits::pause_limits(lua, || receiver.recv())
+        .map_err(|err| LuaError::RuntimeError(format!("failed to get dialog response: {err}")))
+}
+
+/// Register `ui_*` functions in the lua engine
+///
+/// In headless mode dialogs are not shown, and the functions return
+/// the default answer given by the script, or `false` and the first option
+pub fn register(lua: &Lua, standard: IntegrationStandard, game_name: &str) -> LuaResult<()> {
+    let globals = lua.globals();
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("ui_confirm"), lua.create_function(move |lua, (title, message, default): (String, String, Option<bool>)| {
+        let default = default.unwrap_or(false);
+
+        if !is_interactive(lua) {
+            tracing::debug!(target: "integration", game = %game, "Using default answer '{default}' for '{title}' dialog in headless mode");
+
+            return Ok(default);
+        }
+
+        let responses = vec![
+            (String::from("no"), tr!("dialog-no")),
+            (String::from("yes"), tr!("dialog-yes"))
+        ];
+
+        let default = if default { "yes" } else { "no" };
+
+        Ok(show_dialog(lua, title, message, responses, default.to_string())? == "yes")
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("ui_choose"), lua.create_function(move |lua, (title, message, options, default): (String, String, LuaTable, Option<String>)| {
+        let options = options.sequence_values::<LuaTable>()
+            .map(|option| {
+                let option = option?;
+
+                Ok((option.get::<_, String>("name")?, option.get::<_, String>("title")?))
+            })
+            .collect::<LuaResult<Vec<_>>>()?;
+
+        let default = match default.or_else(|| options.first().map(|(name, _)| name.clone())) {
+            Some(default) => default,
+            None => return Err(LuaError::RuntimeError(String::from("at least one option must be given")))
+        };
+
+        if !options.iter().any(|(name, _)| name == &default) {
+            return Err(LuaError::RuntimeError(format!("default option '{default}' is not in the options list")));
+        }
+
+        if !is_interactive(lua) {
+            tracing::debug!(target: "integration", game = %game, "Using default option '{default}' for '{title}' dialog in headless mode");
+
+            return Ok(default);
+        }
+
+        show_dialog(lua, title, message, options, default)
+    })?)?;
+
+    let game = game_name.to_string();
+
+    globals.set(standard.function_name("ui_notify"), lua.create_function(move |lua, (title, message): (String, String)| {
+        if !is_interactive(lua) {
+            tracing::info!(target: "integration", game = %game, "{title}: {message}");
+
+            return Ok(());
+        }
+
+        let responses = vec![
+            (String::from("close"), tr!("dialog-close"))
+        ];
+
+        show_dialog(lua, title, message, responses, String::from("close"))?;
+
+        Ok(())
+    })?)?;
+
+    Ok(())
+}
@@ -1,68 +1,341 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
 
 use crate::config;
+use crate::APP_VERSION;
+
+use integrations::manifest::Manifest;
 
 pub mod integrations;
 pub mod metadata;
+pub mod watcher;
+
+static GAMES_SINGLETON: RwLock<Option<HashMap<String, Arc<integrations::Game>>>> = RwLock::new(None);
+static SKIPPED_SINGLETON: RwLock<Vec<SkippedIntegration>> = RwLock::new(Vec::new());
+static BROKEN_SINGLETON: RwLock<Vec<BrokenIntegration>> = RwLock::new(Vec::new());
+
+/// Integration which wasn't loaded because it's incompatible with the launcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedIntegration {
+    /// Name of the integration folder
+    pub name: String,
+
+    pub game_title: String,
+    pub reason: String
+}
+
+/// Integration which failed to load
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenIntegration {
+    /// Name of the integration folder
+    pub name: String,
+
+    /// Path to the integration folder
+    pub path: PathBuf,
+
+    pub error: String
+}
+
+/// Result of loading the integration from its folder
+#[derive(Debug)]
+pub enum LoadResult {
+    Loaded(Arc<integrations::Game>),
+    Skipped(SkippedIntegration)
+}
+
+/// Integration loaded from its folder but not registered yet
+#[derive(Debug)]
+pub struct LoadedIntegration {
+    /// Name of the integration folder
+    pub name: String,
 
-static mut GAMES_SINGLETON: Option<HashMap<String, integrations::Game>> = None;
+    /// Path to the integration folder
+    pub path: PathBuf,
+
+    /// `None` if the integration folder was removed
+    pub result: Option<anyhow::Result<LoadResult>>
+}
+
+fn load_integration(name: &str, path: &Path) -> anyhow::Result<LoadResult> {
+    let manifest_path = path.join("manifest.json");
+    let manifest = Manifest::from_file(&manifest_path)?;
+
+    if let Err(err) = manifest.check_launcher_version(APP_VERSION) {
+        tracing::warn!("Skipping {} integration: {err}", manifest.game_name);
+
+        return Ok(LoadResult::Skipped(SkippedIntegration {
+            name: name.to_string(),
+            game_title: manifest.game_title.clone(),
+            reason: err.to_string()
+        }));
+    }
+
+    let game = integrations::Game::from_manifest(manifest, manifest_path)?;
+
+    Ok(LoadResult::Loaded(Arc::new(game)))
+}
+
+/// Put skipped integration to the list, replacing its previous entry
+fn set_skipped(integration: SkippedIntegration) {
+    let mut skipped = SKIPPED_SINGLETON.write().unwrap();
+
+    skipped.retain(|skipped| skipped.name != integration.name);
+    skipped.push(integration);
+}
 
 pub fn init() -> anyhow::Result<()> {
     let integration_scripts = config::get().games.integrations.path;
 
+    // Folder is not created if no integration was downloaded yet
+    std::fs::create_dir_all(&integration_scripts)?;
+
     let mut games = HashMap::new();
+    let mut skipped = Vec::new();
+    let mut broken = Vec::new();
 
     for entry in integration_scripts.read_dir()?.flatten() {
         if entry.path().is_dir() {
-            let game = integrations::Game::new(entry.path().join("manifest.json"))?;
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            // Broken integration shouldn't stop the whole launcher
+            match load_integration(&name, &entry.path()) {
+                Ok(LoadResult::Loaded(game)) => {
+                    games.insert(name, game);
+                }
+
+                Ok(LoadResult::Skipped(integration)) => skipped.push(integration),
+
+                Err(err) => {
+                    tracing::error!("Failed to load {name} integration: {err}");
+
+                    broken.push(BrokenIntegration {
+                        name,
+                        path: entry.path(),
+                        error: err.to_string()
+                    });
+                }
+            }
+        }
+    }
+
+    *GAMES_SINGLETON.write().unwrap() = Some(games);
+    *SKIPPED_SINGLETON.write().unwrap() = skipped;
+    *BROKEN_SINGLETON.write().unwrap() = broken;
+
+    Ok(())
+}
+
+/// Get list of integrations which are not compatible with the launcher
+#[inline]
+pub fn skipped() -> Vec<SkippedIntegration> {
+    SKIPPED_SINGLETON.read().unwrap().clone()
+}
+
+/// Get list of integrations which failed to load
+#[inline]
+pub fn broken() -> Vec<BrokenIntegration> {
+    BROKEN_SINGLETON.read().unwrap().clone()
+}
+
+/// Try to load broken integration again
+///
+/// Return loaded game, or `None` if the integration
+/// is not compatible with the launcher
+pub fn retry_broken(name: impl AsRef<str>) -> anyhow::Result<Option<Arc<integrations::Game>>> {
+    let name = name.as_ref();
+
+    let Some(path) = BROKEN_SINGLETON.read().unwrap().iter()
+        .find(|integration| integration.name == name)
+        .map(|integration| integration.path.clone()) else {
+        anyhow::bail!("Integration {name} is not broken");
+    };
+
+    let result = load_integration(name, &path);
+
+    let mut broken = BROKEN_SINGLETON.write().unwrap();
+
+    match result {
+        Ok(LoadResult::Loaded(game)) => {
+            broken.retain(|integration| integration.name != name);
+
+            GAMES_SINGLETON.write().unwrap()
+                .get_or_insert_with(HashMap::new)
+                .insert(name.to_string(), game.clone());
+
+            Ok(Some(game))
+        }
+
+        Ok(LoadResult::Skipped(integration)) => {
+            broken.retain(|integration| integration.name != name);
+
+            set_skipped(integration);
+
+            Ok(None)
+        }
+
+        Err(err) => {
+            if let Some(integration) = broken.iter_mut().find(|integration| integration.name == name) {
+                integration.error = err.to_string();
+            }
 
-            games.insert(entry.file_name().to_string_lossy().to_string(), game);
+            Err(err)
         }
     }
+}
+
+/// Delete broken integration's folder
+pub fn remove_broken(name: impl AsRef<str>) -> anyhow::Result<()> {
+    let mut broken = BROKEN_SINGLETON.write().unwrap();
+
+    let Some(index) = broken.iter().position(|integration| integration.name == name.as_ref()) else {
+        anyhow::bail!("Integration {} is not broken", name.as_ref());
+    };
+
+    let path = &broken[index].path;
+
+    // Local integrations can be symlinked to the launcher
+    if path.is_symlink() {
+        std::fs::remove_file(path)?;
+    }
 
-    unsafe {
-        GAMES_SINGLETON = Some(games);
+    else {
+        std::fs::remove_dir_all(path)?;
     }
 
+    broken.remove(index);
+
     Ok(())
 }
 
-pub fn get<'a>(name: impl AsRef<str>) -> anyhow::Result<Option<&'a integrations::Game>> {
-    unsafe {
-        let Some(singleton) = &mut GAMES_SINGLETON else {
-            init()?;
+/// Load integration from its folder without registering it
+///
+/// Integration scripts are executed during loading,
+/// so this function shouldn't be called from the GTK main thread
+pub fn load(name: impl AsRef<str>) -> LoadedIntegration {
+    let name = name.as_ref().to_string();
+    let path = config::get().games.integrations.path.join(&name);
+
+    let result = path.is_dir().then(|| load_integration(&name, &path));
+
+    LoadedIntegration {
+        name,
+        path,
+        result
+    }
+}
+
+/// Replace registered integration by the loaded one
+///
+/// Previous version of the game is dropped when all
+/// the running tasks release their references to it
+///
+/// Return `None` if the integration was removed
+/// or it's not compatible with the launcher
+pub fn replace(integration: LoadedIntegration) -> anyhow::Result<Option<Arc<integrations::Game>>> {
+    let LoadedIntegration { name, path, result } = integration;
+
+    let previous = GAMES_SINGLETON.write().unwrap()
+        .get_or_insert_with(HashMap::new)
+        .remove(&name);
+
+    if let Some(game) = previous {
+        game.driver.flush_cache();
+    }
+
+    BROKEN_SINGLETON.write().unwrap().retain(|integration| integration.name != name);
+    SKIPPED_SINGLETON.write().unwrap().retain(|integration| integration.name != name);
+
+    match result {
+        None => Ok(None),
 
-            return get(name);
-        };
+        Some(Ok(LoadResult::Loaded(game))) => {
+            GAMES_SINGLETON.write().unwrap()
+                .get_or_insert_with(HashMap::new)
+                .insert(name, game.clone());
 
-        if let Some(result) = singleton.get(name.as_ref()) {
-            return Ok(Some(result));
+            Ok(Some(game))
         }
 
-        Ok(None)
+        Some(Ok(LoadResult::Skipped(integration))) => {
+            set_skipped(integration);
+
+            Ok(None)
+        }
+
+        Some(Err(err)) => {
+            BROKEN_SINGLETON.write().unwrap().push(BrokenIntegration {
+                name,
+                path,
+                error: err.to_string()
+            });
+
+            Err(err)
+        }
+    }
+}
+
+pub fn get(name: impl AsRef<str>) -> anyhow::Result<Option<Arc<integrations::Game>>> {
+    if let Some(singleton) = GAMES_SINGLETON.read().unwrap().as_ref() {
+        return Ok(singleton.get(name.as_ref()).cloned());
     }
+
+    init()?;
+
+    get(name)
 }
 
-/// # Safety
-/// 
-/// This function is called by the game cards which are generated from the `games::list()` method,
-/// so every `get_unsafe()` call will contain an actual game's name
-pub unsafe fn get_unsafe<'a>(name: impl AsRef<str>) -> &'a integrations::Game {
-    GAMES_SINGLETON.as_ref()
-        .unwrap_unchecked()
-        .get(name.as_ref())
-        .unwrap_unchecked()
+/// Get loaded game
+///
+/// Fails if the integration was removed or reloaded with errors
+pub fn get_loaded(name: impl AsRef<str>) -> anyhow::Result<Arc<integrations::Game>> {
+    get(name.as_ref())?.ok_or_else(|| anyhow::anyhow!("Integration {} is not loaded", name.as_ref()))
 }
 
-pub fn list<'a>() -> anyhow::Result<&'a HashMap<String, integrations::Game>> {
-    unsafe {
-        match &GAMES_SINGLETON {
-            Some(singleton) => Ok(singleton),
-            None => {
-                init()?;
+pub fn list() -> anyhow::Result<HashMap<String, Arc<integrations::Game>>> {
+    if let Some(singleton) = GAMES_SINGLETON.read().unwrap().as_ref() {
+        return Ok(singleton.clone());
+    }
+
+    init()?;
 
-                list()
+    list()
+}
+
+/// Get installed editions of all the games
+///
+/// Games which failed to report their editions
+/// or installation status are skipped
+pub fn installed_editions() -> anyhow::Result<Vec<(Arc<integrations::Game>, integrations::standards::game::Edition)>> {
+    let config = config::get();
+
+    let mut installed = Vec::new();
+
+    for game in list()?.into_values() {
+        let editions = (|| -> anyhow::Result<Vec<integrations::standards::game::Edition>> {
+            let settings = config.games.get_game_settings(&game)?;
+
+            let mut editions = Vec::new();
+
+            for edition in game.driver.get_game_editions_list()? {
+                let Some(paths) = settings.paths.get(&edition.name) else {
+                    continue;
+                };
+
+                if game.driver.is_game_installed(&paths.game.to_string_lossy(), &edition.name)? {
+                    editions.push(edition);
+                }
             }
+
+            Ok(editions)
+        })();
+
+        match editions {
+            Ok(editions) => installed.extend(editions.into_iter().map(|edition| (game.clone(), edition))),
+
+            Err(err) => tracing::warn!(game = %game.manifest.game_name, "Failed to get installed editions: {err}")
         }
     }
+
+    Ok(installed)
 }
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Delay between integrations folder checks
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Get the latest modification time of the files in the integration folder
+///
+/// Subfolders are checked recursively, but symlinks inside of the folder
+/// are not followed, so watching doesn't leave the integration's tree
+fn get_modification_time(path: &Path) -> Option<SystemTime> {
+    let mut time = path.metadata().ok()?.modified().ok()?;
+
+    for entry in path.read_dir().ok()?.flatten() {
+        // DirEntry::metadata and DirEntry::file_type don't traverse symlinks
+        if let Some(entry_time) = entry.metadata().ok().and_then(|metadata| metadata.modified().ok()) {
+            time = time.max(entry_time);
+        }
+
+        if entry.file_type().map(|file_type| file_type.is_dir()).unwrap_or(false) {
+            if let Some(entry_time) = get_modification_time(&entry.path()) {
+                time = time.max(entry_time);
+            }
+        }
+    }
+
+    Some(time)
+}
+
+/// Get modification times of all the integrations in the folder
+fn get_integrations_state(path: &Path) -> HashMap<String, SystemTime> {
+    let Ok(entries) = path.read_dir() else {
+        return HashMap::new();
+    };
+
+    entries.flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            // Local integrations are symlinked to the integrations folder,
+            // so their own folders are followed
+            get_modification_time(&entry.path()).map(|time| (name, time))
+        })
+        .collect()
+}
+
+/// Spawn thread which watches integrations folder and calls
+/// the callback with a name of every added, changed or removed integration
+pub fn watch(path: impl AsRef<Path>, callback: impl Fn(String) + Send + 'static) -> anyhow::Result<()> {
+    let path = path.as_ref().to_path_buf();
+
+    std::thread::Builder::new()
+        .name(String::from("integrations-watcher"))
+        .spawn(move || {
+            let mut state = get_integrations_state(&path);
+
+            loop {
+                std::thread::sleep(POLL_INTERVAL);
+
+                let new_state = get_integrations_state(&path);
+
+                for (name, time) in &new_state {
+                    if state.get(name) != Some(time) {
+                        tracing::debug!("Integration {name} was changed");
+
+                        callback(name.clone());
+                    }
+                }
+
+                for name in state.keys() {
+                    if !new_state.contains_key(name) {
+                        tracing::debug!("Integration {name} was removed");
+
+                        callback(name.clone());
+                    }
+                }
+
+                state = new_state;
+            }
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use anime_game_core::network::minreq;
+
+use crate::IMAGES_CACHE_FOLDER;
+
+/// Timeout of the picture downloading, in seconds
+const DOWNLOAD_TIMEOUT: u64 = 15;
+
+#[inline]
+fn get_cache_path(uri: &str) -> PathBuf {
+    let hash = xxhash_rust::xxh3::xxh3_128(uri.as_bytes());
+
+    IMAGES_CACHE_FOLDER.join(format!("{hash:032x}"))
+}
+
+/// Get local path to the picture
+///
+/// Remote pictures are downloaded to the disk cache once and reused
+/// afterwards, including the offline mode. Local paths and app resources
+/// are returned as is
+pub fn get(uri: &str) -> anyhow::Result<String> {
+    if !uri.starts_with("http://") && !uri.starts_with("https://") {
+        return Ok(uri.to_string());
+    }
+
+    let path = get_cache_path(uri);
+
+    if path.exists() {
+        return Ok(path.to_string_lossy().to_string());
+    }
+
+    if crate::offline::is_offline() {
+        anyhow::bail!("Picture {uri} is not cached and can't be downloaded in offline mode");
+    }
+
+    tracing::trace!("Downloading picture {uri} to {path:?}");
+
+    let response = minreq::get(uri)
+        .with_timeout(DOWNLOAD_TIMEOUT)
+        .send()?;
+
+    if !(200..300).contains(&response.status_code) {
+        anyhow::bail!("Failed to download picture {uri}: {} {}", response.status_code, response.reason_phrase);
+    }
+
+    std::fs::create_dir_all(IMAGES_CACHE_FOLDER.as_path())?;
+
+    // Write to the temp file first so other threads
+    // never read partially downloaded pictures
+    let temp_path = path.with_extension("part");
+
+    std::fs::write(&temp_path, response.as_bytes())?;
+    std::fs::rename(temp_path, &path)?;
+
+    Ok(path.to_string_lossy().to_string())
+}
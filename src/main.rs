@@ -8,6 +8,9 @@ pub mod config;
 pub mod games;
 pub mod components;
 pub mod ui;
+pub mod cli;
+pub mod offline;
+pub mod images;
 
 use ui::windows::loading::LoadingApp;
 
@@ -44,12 +47,28 @@ lazy_static::lazy_static! {
 
     /// Path to launcher's debug log file
     pub static ref DEBUG_FILE: PathBuf = LAUNCHER_FOLDER.join("debug.log");
+
+    /// Path to the cached network responses of integration scripts
+    pub static ref NETWORK_CACHE_FOLDER: PathBuf = LAUNCHER_FOLDER.join("cache").join("network");
+
+    /// Path to the cached pictures, e.g. games news images
+    pub static ref IMAGES_CACHE_FOLDER: PathBuf = LAUNCHER_FOLDER.join("cache").join("images");
+
+    /// Path to the folder with rejected integrations
+    pub static ref QUARANTINE_FOLDER: PathBuf = LAUNCHER_FOLDER.join("quarantine");
 }
 
 fn main() -> anyhow::Result<()> {
     // Setup custom panic handler
     human_panic::setup_panic!(human_panic::metadata!());
 
+    // Run command line command without GTK session
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    if let Some(code) = cli::run(&args) {
+        std::process::exit(code);
+    }
+
     adw::init().expect("Libadwaita initialization failed");
 
     // Register and include resources
@@ -0,0 +1,34 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anime_game_core::network::minreq;
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Check if the launcher works in offline mode
+///
+/// In offline mode remote updates are skipped, components
+/// are resolved from the installed ones, and integration scripts
+/// use cached network responses when available
+#[inline]
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Switch launcher to the offline mode
+pub fn enable(reason: impl std::fmt::Display) {
+    if !OFFLINE.swap(true, Ordering::Relaxed) {
+        tracing::warn!("Switching to offline mode: {reason}");
+    }
+}
+
+/// Check if the offline mode is forced by the `--offline` flag
+#[inline]
+pub fn is_forced() -> bool {
+    std::env::args().any(|arg| arg == "--offline")
+}
+
+/// Check if the error is caused by the network failure
+#[inline]
+pub fn is_network_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|err| err.is::<minreq::Error>())
+}
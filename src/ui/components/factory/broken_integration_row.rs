@@ -0,0 +1,79 @@
+use relm4::prelude::*;
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::games::BrokenIntegration;
+
+#[derive(Debug)]
+pub struct BrokenIntegrationFactory {
+    pub name: String,
+    pub error: String
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrokenIntegrationFactoryInput {
+    SetError(String)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrokenIntegrationFactoryOutput {
+    Retry(String),
+    Remove(String)
+}
+
+#[relm4::factory(pub)]
+impl FactoryComponent for BrokenIntegrationFactory {
+    type Init = BrokenIntegration;
+    type Input = BrokenIntegrationFactoryInput;
+    type Output = BrokenIntegrationFactoryOutput;
+    type CommandOutput = ();
+    type ParentWidget = gtk::ListBox;
+
+    view! {
+        root = adw::ActionRow {
+            set_title: &self.name,
+
+            #[watch]
+            set_subtitle: &self.error,
+
+            add_suffix = &gtk::Button {
+                set_valign: gtk::Align::Center,
+                add_css_class: "flat",
+
+                set_label: &tr!("main-broken-integrations-retry"),
+
+                connect_clicked[sender, name = self.name.clone()] => move |_| {
+                    sender.output(BrokenIntegrationFactoryOutput::Retry(name.clone())).unwrap();
+                }
+            },
+
+            add_suffix = &gtk::Button {
+                set_valign: gtk::Align::Center,
+                add_css_class: "flat",
+                add_css_class: "destructive-action",
+
+                set_label: &tr!("main-broken-integrations-remove"),
+
+                connect_clicked[sender, name = self.name.clone()] => move |_| {
+                    sender.output(BrokenIntegrationFactoryOutput::Remove(name.clone())).unwrap();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn init_model(init: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self {
+            name: init.name,
+            error: init.error
+        }
+    }
+
+    #[inline]
+    fn update(&mut self, msg: Self::Input, _sender: FactorySender<Self>) {
+        match msg {
+            BrokenIntegrationFactoryInput::SetError(error) => self.error = error
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod game_card_main;
 pub mod game_card_tasks;
+pub mod broken_integration_row;
@@ -1,6 +1,9 @@
 use relm4::prelude::*;
 use gtk::prelude::*;
 
+use crate::tr;
+use crate::games;
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum CardInfo {
     Game {
@@ -70,6 +73,15 @@ impl CardInfo {
     }
 }
 
+/// Check if the card's game is loaded from the local integrations source
+#[inline]
+fn is_local_integration(name: &str) -> bool {
+    games::get(name).ok()
+        .flatten()
+        .map(|game| game.is_local)
+        .unwrap_or(false)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CardComponent {
     pub info: CardInfo,
@@ -213,6 +225,13 @@ impl SimpleAsyncComponent for CardComponent {
                     gtk::Label {
                         #[watch]
                         set_markup: &format!("  <span foreground=\"grey\">({})</span>", model.info.get_edition())
+                    },
+
+                    gtk::Label {
+                        #[watch]
+                        set_visible: is_local_integration(model.info.get_name()),
+
+                        set_markup: &format!("  <span foreground=\"orange\">{}</span>", tr!("game-card-dev"))
                     }
                 }
             }
@@ -3,7 +3,13 @@ use gtk::prelude::*;
 
 use crate::tr;
 
+use crate::games;
+use crate::images;
+use crate::i18n;
+
 use crate::games::metadata::LauncherMetadata;
+use crate::games::integrations::manifest::ManifestMetadata;
+use crate::games::integrations::standards::news::NewsItem;
 use crate::games::integrations::standards::game::{
     Status,
     StatusSeverity
@@ -15,16 +21,27 @@ use crate::ui::components::game_card::{
     CardComponentInput
 };
 
+/// Width of the news card, in pixels
+const NEWS_CARD_WIDTH: i32 = 320;
+
+/// Height of the news picture, in pixels
+const NEWS_PICTURE_HEIGHT: i32 = 180;
+
 #[derive(Debug)]
 pub struct GameDetailsComponent {
     pub game_card: AsyncController<CardComponent>,
 
     pub info: CardInfo,
     pub metadata: LauncherMetadata,
+    pub manifest_metadata: ManifestMetadata,
 
     pub installed: bool,
     pub running: bool,
-    pub status: Option<Status>
+    pub status: Option<Status>,
+
+    pub news: Vec<NewsItem>,
+    pub news_box: gtk::Box,
+    pub news_pictures: Vec<gtk::Picture>
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -35,6 +52,21 @@ pub enum GameDetailsComponentInput {
     SetRunning(bool),
     SetStatus(Option<Status>),
 
+    SetNews {
+        info: CardInfo,
+        news: Vec<NewsItem>
+    },
+
+    SetNewsPicture {
+        info: CardInfo,
+        index: usize,
+        picture: String
+    },
+
+    OpenNewsLink(String),
+    OpenHomepage,
+    OpenSupport,
+
     EditCard(CardComponentInput),
 
     EmitDownloadGame,
@@ -103,6 +135,75 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                     })
                 },
 
+                gtk::Label {
+                    set_halign: gtk::Align::Start,
+                    set_xalign: 0.0,
+
+                    set_margin_top: 16,
+                    set_max_width_chars: 60,
+                    set_wrap: true,
+
+                    #[watch]
+                    set_visible: model.manifest_metadata.description.is_some(),
+
+                    #[watch]
+                    set_label: model.manifest_metadata.description.as_deref().unwrap_or_default()
+                },
+
+                gtk::Label {
+                    set_halign: gtk::Align::Start,
+
+                    set_margin_top: 8,
+
+                    add_css_class: "dim-label",
+
+                    #[watch]
+                    set_visible: !model.manifest_metadata.tags.is_empty(),
+
+                    #[watch]
+                    set_label: &tr!("details-tags", {
+                        "tags" = model.manifest_metadata.tags.join(", ")
+                    })
+                },
+
+                gtk::Box {
+                    set_valign: gtk::Align::Center,
+
+                    set_margin_top: 16,
+                    set_spacing: 8,
+
+                    #[watch]
+                    set_visible: model.manifest_metadata.homepage.is_some() || model.manifest_metadata.support.is_some(),
+
+                    gtk::Button {
+                        add_css_class: "pill",
+
+                        adw::ButtonContent {
+                            set_icon_name: "go-home-symbolic",
+                            set_label: &tr!("details-homepage")
+                        },
+
+                        #[watch]
+                        set_visible: model.manifest_metadata.homepage.is_some(),
+
+                        connect_clicked => GameDetailsComponentInput::OpenHomepage
+                    },
+
+                    gtk::Button {
+                        add_css_class: "pill",
+
+                        adw::ButtonContent {
+                            set_icon_name: "help-about-symbolic",
+                            set_label: &tr!("details-support")
+                        },
+
+                        #[watch]
+                        set_visible: model.manifest_metadata.support.is_some(),
+
+                        connect_clicked => GameDetailsComponentInput::OpenSupport
+                    }
+                },
+
                 gtk::Box {
                     set_orientation: gtk::Orientation::Vertical,
 
@@ -250,6 +351,36 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                             connect_clicked => GameDetailsComponentInput::EmitDownloadGame
                         }
                     }
+                },
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+
+                    set_margin_top: 36,
+
+                    #[watch]
+                    set_visible: !model.news.is_empty(),
+
+                    gtk::Label {
+                        set_halign: gtk::Align::Start,
+
+                        add_css_class: "title-4",
+
+                        set_label: &tr!("details-news")
+                    },
+
+                    gtk::ScrolledWindow {
+                        set_margin_top: 12,
+                        set_width_request: NEWS_CARD_WIDTH * 2,
+
+                        set_policy: (gtk::PolicyType::Automatic, gtk::PolicyType::Never),
+
+                        #[local_ref]
+                        news_box -> gtk::Box {
+                            set_spacing: 16,
+                            set_margin_bottom: 12
+                        }
+                    }
                 }
             }
         }
@@ -263,15 +394,22 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
             info: init,
             metadata: LauncherMetadata::default(),
+            manifest_metadata: ManifestMetadata::default(),
 
             installed: false,
             running: false,
-            status: None
+            status: None,
+
+            news: Vec::new(),
+            news_box: gtk::Box::new(gtk::Orientation::Horizontal, 0),
+            news_pictures: Vec::new()
         };
 
         model.game_card.emit(CardComponentInput::SetClickable(false));
         model.game_card.emit(CardComponentInput::SetDisplayTitle(false));
 
+        let news_box = &model.news_box;
+
         let widgets = view_output!();
 
         AsyncComponentParts { model, widgets }
@@ -282,6 +420,17 @@ impl SimpleAsyncComponent for GameDetailsComponent {
             GameDetailsComponentInput::SetInfo(info) => {
                 self.info = info.clone();
 
+                self.manifest_metadata = games::get(info.get_name()).ok()
+                    .flatten()
+                    .map(|game| game.manifest.metadata.clone())
+                    .unwrap_or_default();
+
+                self.set_news(Vec::new(), &sender);
+
+                if let CardInfo::Game { .. } = &info {
+                    load_news(info.clone(), sender.input_sender().clone());
+                }
+
                 self.game_card.emit(CardComponentInput::SetInfo(info));
             }
 
@@ -296,6 +445,54 @@ impl SimpleAsyncComponent for GameDetailsComponent {
             GameDetailsComponentInput::SetRunning(running) => self.running = running,
             GameDetailsComponentInput::SetStatus(status) => self.status = status,
 
+            GameDetailsComponentInput::SetNews { info, news } => {
+                // News of the previously opened game
+                if info == self.info {
+                    self.set_news(news, &sender);
+                }
+            }
+
+            GameDetailsComponentInput::SetNewsPicture { info, index, picture } => {
+                if info != self.info {
+                    return;
+                }
+
+                if let Some(widget) = self.news_pictures.get(index) {
+                    if picture.starts_with(crate::APP_RESOURCE_PREFIX) {
+                        widget.set_resource(Some(&picture));
+                    }
+
+                    else {
+                        widget.set_filename(Some(&picture));
+                    }
+
+                    widget.set_visible(true);
+                }
+            }
+
+            GameDetailsComponentInput::OpenNewsLink(link) => {
+                if let Err(err) = open_web_link(&link) {
+                    sender.output(GameDetailsComponentOutput::ShowToast {
+                        title: tr!("details-open-news-failed"),
+                        message: Some(err.to_string())
+                    }).unwrap();
+
+                    tracing::error!("Failed to open news link {link}: {err}");
+                }
+            }
+
+            GameDetailsComponentInput::OpenHomepage => {
+                if let Some(link) = &self.manifest_metadata.homepage {
+                    open_link(link, &sender);
+                }
+            }
+
+            GameDetailsComponentInput::OpenSupport => {
+                if let Some(link) = &self.manifest_metadata.support {
+                    open_link(link, &sender);
+                }
+            }
+
             GameDetailsComponentInput::EditCard(message) => self.game_card.emit(message),
 
             GameDetailsComponentInput::EmitDownloadGame => {
@@ -326,3 +523,179 @@ impl SimpleAsyncComponent for GameDetailsComponent {
         }
     }
 }
+
+impl GameDetailsComponent {
+    /// Replace displayed news feed
+    fn set_news(&mut self, news: Vec<NewsItem>, sender: &AsyncComponentSender<Self>) {
+        while let Some(child) = self.news_box.first_child() {
+            self.news_box.remove(&child);
+        }
+
+        self.news_pictures.clear();
+
+        for item in &news {
+            let (card, picture) = build_news_card(item, sender);
+
+            self.news_box.append(&card);
+            self.news_pictures.push(picture);
+        }
+
+        self.news = news;
+    }
+}
+
+/// Open integration's homepage or support link
+fn open_link(link: &str, sender: &AsyncComponentSender<GameDetailsComponent>) {
+    if let Err(err) = open_web_link(link) {
+        sender.output(GameDetailsComponentOutput::ShowToast {
+            title: tr!("details-open-link-failed"),
+            message: Some(err.to_string())
+        }).unwrap();
+
+        tracing::error!("Failed to open link {link}: {err}");
+    }
+}
+
+/// Open http(s) link in the default browser
+///
+/// Links are given by the integration scripts, and other schemes
+/// could be used to open local files or run applications
+fn open_web_link(link: &str) -> anyhow::Result<()> {
+    let scheme = link.split_once("://")
+        .map(|(scheme, _)| scheme.to_ascii_lowercase());
+
+    if !matches!(scheme.as_deref(), Some("http" | "https")) {
+        anyhow::bail!("Only http and https links are allowed: {link}");
+    }
+
+    open::that(link)?;
+
+    Ok(())
+}
+
+/// Load game news and their pictures in background
+///
+/// Integration scripts and pictures downloading can take
+/// a lot of time, so the results are sent back one by one
+fn load_news(info: CardInfo, sender: relm4::Sender<GameDetailsComponentInput>) {
+    std::thread::spawn(move || {
+        let Ok(Some(game)) = games::get(info.get_name()) else {
+            return;
+        };
+
+        let language = i18n::format_language(unsafe { i18n::get_lang() });
+
+        let news = match game.driver.get_news(info.get_edition(), &language) {
+            Ok(news) => news,
+
+            Err(err) => {
+                tracing::warn!("Failed to get {} news: {err}", info.get_name());
+
+                return;
+            }
+        };
+
+        let pictures = news.iter()
+            .map(|item| item.image.clone())
+            .collect::<Vec<_>>();
+
+        sender.emit(GameDetailsComponentInput::SetNews {
+            info: info.clone(),
+            news
+        });
+
+        for (index, uri) in pictures.into_iter().enumerate() {
+            let Some(uri) = uri else {
+                continue;
+            };
+
+            match images::get(&uri) {
+                Ok(picture) => sender.emit(GameDetailsComponentInput::SetNewsPicture {
+                    info: info.clone(),
+                    index,
+                    picture
+                }),
+
+                Err(err) => tracing::warn!("Failed to load news picture {uri}: {err}")
+            }
+        }
+    });
+}
+
+/// Build news feed card
+///
+/// Picture is hidden until it's loaded
+fn build_news_card(item: &NewsItem, sender: &AsyncComponentSender<GameDetailsComponent>) -> (gtk::Box, gtk::Picture) {
+    let card = gtk::Box::new(gtk::Orientation::Vertical, 0);
+
+    card.set_width_request(NEWS_CARD_WIDTH);
+    card.set_overflow(gtk::Overflow::Hidden);
+    card.add_css_class("card");
+
+    let picture = gtk::Picture::builder()
+        .content_fit(gtk::ContentFit::Cover)
+        .height_request(NEWS_PICTURE_HEIGHT)
+        .visible(false)
+        .build();
+
+    let title = gtk::Label::builder()
+        .label(&item.title)
+        .halign(gtk::Align::Start)
+        .xalign(0.0)
+        .wrap(true)
+        .margin_top(12)
+        .margin_start(12)
+        .margin_end(12)
+        .build();
+
+    title.add_css_class("heading");
+
+    card.append(&picture);
+    card.append(&title);
+
+    if let Some(summary) = &item.summary {
+        let summary = gtk::Label::builder()
+            .label(summary)
+            .halign(gtk::Align::Start)
+            .xalign(0.0)
+            .wrap(true)
+            .lines(3)
+            .ellipsize(gtk::pango::EllipsizeMode::End)
+            .margin_top(8)
+            .margin_start(12)
+            .margin_end(12)
+            .build();
+
+        card.append(&summary);
+    }
+
+    let date = gtk::Label::builder()
+        .label(item.date.as_deref().unwrap_or_default())
+        .halign(gtk::Align::Start)
+        .margin_top(8)
+        .margin_start(12)
+        .margin_end(12)
+        .margin_bottom(12)
+        .build();
+
+    date.add_css_class("caption");
+    date.add_css_class("dim-label");
+
+    card.append(&date);
+
+    if let Some(link) = item.link.clone() {
+        let sender = sender.input_sender().clone();
+        let gesture = gtk::GestureClick::new();
+
+        card.set_tooltip_text(Some(&link));
+        card.set_cursor_from_name(Some("pointer"));
+
+        gesture.connect_released(move |_, _, _, _| {
+            sender.emit(GameDetailsComponentInput::OpenNewsLink(link.clone()));
+        });
+
+        card.add_controller(gesture);
+    }
+
+    (card, picture)
+}
@@ -10,6 +10,7 @@ use anime_game_core::updater::UpdaterExt;
 
 use crate::components::wine::Wine;
 use crate::components::dxvk::Dxvk;
+use crate::components::prefix;
 
 use crate::ui::components::game_card::CardInfo;
 
@@ -20,6 +21,7 @@ pub enum Status {
     CreatingPrefix,
     InstallingDxvk,
     InstallingFonts,
+    ApplyingRequirements,
     Finished
 }
 
@@ -120,7 +122,9 @@ impl QueuedTask for CreatePrefixQueuedTask {
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
         let (sender, receiver) = flume::unbounded();
 
-        let Some(wine) = Wine::from_config()?.to_wincompatlib() else {
+        let wine_component = Wine::from_config()?;
+
+        let Some(wine) = wine_component.to_wincompatlib() else {
             anyhow::bail!("Failed to resolve wincompatlib wine descriptor");
         };
 
@@ -134,6 +138,8 @@ impl QueuedTask for CreatePrefixQueuedTask {
                 updater: receiver,
 
                 worker: Some(std::thread::spawn(move || -> anyhow::Result<()> {
+                    let wine_binary = wine_component.get_executable();
+
                     // Specify basic wine params
 
                     let wine = wine
@@ -168,9 +174,9 @@ impl QueuedTask for CreatePrefixQueuedTask {
 
                     // Install fonts
 
-                    if self.install_corefonts {
-                        let wine_arc = Arc::new(wine);
+                    let wine_arc = Arc::new(wine);
 
+                    if self.install_corefonts {
                         let fonts = Font::iterator().into_iter().collect::<Vec<Font>>();
                         let total_fonts = fonts.len() as u64;
 
@@ -222,6 +228,42 @@ impl QueuedTask for CreatePrefixQueuedTask {
                         sender.send((Status::InstallingFonts, total_fonts, total_fonts))?;
                     }
 
+                    // Apply requirements of the installed games
+
+                    let requirements = prefix::get_installed_requirements()?;
+                    let missing = prefix::get_missing(&self.path, &requirements);
+
+                    if !missing.is_empty() {
+                        let total = (missing.fonts.len() + missing.dll_overrides.len() + missing.verbs.len()) as u64;
+                        let mut current = 0;
+
+                        sender.send((Status::ApplyingRequirements, 0, total))?;
+
+                        for font in &missing.fonts {
+                            prefix::install_font(&wine_arc, font)?;
+
+                            current += 1;
+
+                            sender.send((Status::ApplyingRequirements, current, total))?;
+                        }
+
+                        for (name, mode) in &missing.dll_overrides {
+                            prefix::install_dll_override(&wine_binary, &self.path, name, mode)?;
+
+                            current += 1;
+
+                            sender.send((Status::ApplyingRequirements, current, total))?;
+                        }
+
+                        for verb in &missing.verbs {
+                            prefix::install_verb(&wine_binary, &self.path, verb)?;
+
+                            current += 1;
+
+                            sender.send((Status::ApplyingRequirements, current, total))?;
+                        }
+                    }
+
                     // Finish downloading
 
                     sender.send((Status::Finished, 1, 1))?;
@@ -273,7 +315,9 @@ impl ResolvedTask for CreatePrefixResolvedTask {
                 Status::CreatingPrefix  => TaskStatus::CreatingPrefix,
                 Status::InstallingDxvk  => TaskStatus::InstallingDxvk,
                 Status::InstallingFonts => TaskStatus::InstallingFonts,
-                Status::Finished        => TaskStatus::Finished
+                Status::Finished        => TaskStatus::Finished,
+
+                Status::ApplyingRequirements => TaskStatus::ApplyingRequirements
             }),
 
             Err(err) => anyhow::bail!(err.to_string())
@@ -82,9 +82,7 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
             updater: BasicUpdater::spawn(move |sender| {
                 Box::new(move || -> Result<(), anyhow::Error> {
-                    let game = unsafe {
-                        games::get_unsafe(&game_name)
-                    };
+                    let game = games::get_loaded(&game_name)?;
 
                     // Create transition
 
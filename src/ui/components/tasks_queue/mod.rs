@@ -11,6 +11,8 @@ use gtk::prelude::*;
 
 use crate::tr;
 
+use crate::games::integrations::standards::error as script_error;
+
 use crate::ui::components::game_card::{
     CardInfo,
     CardComponent,
@@ -80,7 +82,10 @@ pub enum TasksQueueComponentOutput {
 
     ShowToast {
         title: String,
-        message: Option<String>
+        message: Option<String>,
+
+        /// Trace of the integration script error
+        trace: Option<String>
     }
 }
 
@@ -299,7 +304,8 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                         Err(err) => {
                             sender.output(TasksQueueComponentOutput::ShowToast {
                                 title: tr!("tasks-resolve-queued-failed"),
-                                message: Some(err.to_string())
+                                message: Some(err.to_string()),
+                                trace: script_error::get_trace(&err)
                             }).unwrap();
                         }
                     }
@@ -324,7 +330,8 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                                 title: tr!("tasks-get-status-failed", {
                                     "game-title" = task.get_info().get_title().to_string()
                                 }),
-                                message: Some(err.to_string())
+                                message: Some(err.to_string()),
+                                trace: script_error::get_trace(&err)
                             }).unwrap();
                         }
 
@@ -355,7 +362,8 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                                 Err(err) => {
                                     sender.output(TasksQueueComponentOutput::ShowToast {
                                         title: tr!("tasks-resolve-queued-failed"),
-                                        message: Some(err.to_string())
+                                        message: Some(err.to_string()),
+                                        trace: script_error::get_trace(&err)
                                     }).unwrap();
                                 }
                             }
@@ -402,6 +410,7 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                                 TaskStatus::CreatingPrefix  => (true, tr!("tasks-creating-prefix")),
                                 TaskStatus::InstallingDxvk  => (true, tr!("tasks-installing-dxvk")),
                                 TaskStatus::InstallingFonts => (false, tr!("tasks-installing-fonts")),
+                                TaskStatus::ApplyingRequirements => (false, tr!("tasks-applying-requirements")),
 
                                 TaskStatus::VerifyingFiles => (false, tr!("tasks-verifying-files")),
                                 TaskStatus::RepairingFiles => (false, tr!("tasks-repairing-files")),
@@ -14,6 +14,7 @@ pub enum TaskStatus {
     CreatingPrefix,
     InstallingDxvk,
     InstallingFonts,
+    ApplyingRequirements,
     VerifyingFiles,
     RepairingFiles,
     DeletingFiles,
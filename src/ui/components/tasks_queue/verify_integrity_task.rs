@@ -67,9 +67,7 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
             updater: BasicUpdater::spawn(move |sender| {
                 Box::new(move || -> Result<(), anyhow::Error> {
-                    let game = unsafe {
-                        games::get_unsafe(&game_name)
-                    };
+                    let game = games::get_loaded(&game_name)?;
 
                     // Check if lua script support custom hashes
                     let has_integrity_hash = game.driver.has_integrity_hash()?;
@@ -135,37 +133,16 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                                 let data = std::fs::read(&integrity_file)?;
 
                                 // Get existing file hash
-                                let hash = match info.hash {
-                                    HashType::Md5 => {
-                                        use md5::{Md5, Digest};
+                                let hash = match info.hash.hash(&data) {
+                                    Some(hash) => hash,
 
-                                        format!("{:x}", Md5::digest(&data))
-                                    },
+                                    None => match info.hash {
+                                        HashType::Custom(name) if has_integrity_hash => {
+                                            game.driver.integrity_hash(&name, data)?
+                                        }
 
-                                    HashType::Sha1 => {
-                                        use sha1::{Sha1, Digest};
-
-                                        format!("{:x}", Sha1::digest(&data))
-                                    },
-
-                                    HashType::Crc32 => {
-                                        let mut hasher = crc32fast::Hasher::new();
-
-                                        hasher.update(&data);
-
-                                        hasher.finalize().to_string()
+                                        _ => unimplemented!()
                                     }
-
-                                    HashType::Xxhash32    => format!("{:x}", xxhash_rust::xxh32::xxh32(&data, 0)),
-                                    HashType::Xxhash64    => format!("{:x}", xxhash_rust::xxh64::xxh64(&data, 0)),
-                                    HashType::Xxhash3_64  => format!("{:x}", xxhash_rust::xxh3::xxh3_64(&data)),
-                                    HashType::Xxhash3_128 => format!("{:x}", xxhash_rust::xxh3::xxh3_128(&data)),
-
-                                    HashType::Custom(name) if has_integrity_hash => {
-                                        game.driver.integrity_hash(&name, data)?
-                                    }
-
-                                    _ => unimplemented!()
                                 };
 
                                 sender.send((
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use serde_json::Value as Json;
+
 use relm4::prelude::*;
 
 use gtk::prelude::*;
@@ -17,6 +19,11 @@ use crate::games::integrations::standards::addons::{
     AddonsGroup
 };
 
+use crate::games::integrations::standards::settings::{
+    SettingsField,
+    SettingsFieldKind
+};
+
 use crate::ui::components::addon::addon_group::{
     AddonsGroupComponent,
     AddonsGroupComponentInit,
@@ -31,6 +38,9 @@ pub static mut WINDOW: Option<adw::Window> = None;
 
 #[derive(Debug)]
 pub struct GameAddonsManagerApp {
+    pub settings_group: adw::PreferencesGroup,
+    pub settings_rows: Vec<adw::PreferencesRow>,
+
     pub addons_groups_widgets: Vec<AsyncController<AddonsGroupComponent>>,
     pub addons_groups_page: adw::PreferencesPage,
 
@@ -43,7 +53,13 @@ pub struct GameAddonsManagerApp {
 pub enum GameAddonsManagerAppMsg {
     SetGameInfo {
         game_info: CardInfo,
-        addons: Vec<AddonsGroup>
+        addons: Vec<AddonsGroup>,
+        settings: Vec<SettingsField>
+    },
+
+    SetSetting {
+        name: String,
+        value: Json
     },
 
     InstallAddon {
@@ -93,6 +109,9 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
 
     async fn init(parent: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
         let model = Self {
+            settings_group: adw::PreferencesGroup::new(),
+            settings_rows: Vec::new(),
+
             addons_groups_widgets: Vec::new(),
             addons_groups_page: adw::PreferencesPage::new(),
 
@@ -103,6 +122,11 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
 
         let addons_groups_page = &model.addons_groups_page;
 
+        model.settings_group.set_title(&tr!("game-settings"));
+        model.settings_group.set_visible(false);
+
+        addons_groups_page.add(&model.settings_group);
+
         let widgets = view_output!();
 
         widgets.window.set_transient_for(Some(&parent));
@@ -116,13 +140,21 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
 
     async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
         match msg {
-            GameAddonsManagerAppMsg::SetGameInfo { game_info, addons } => {
-                let game = unsafe {
-                    games::get_unsafe(game_info.get_name())
+            GameAddonsManagerAppMsg::SetGameInfo { game_info, addons, settings: settings_schema } => {
+                // Main window checks that the game is loaded before opening the manager,
+                // but the integration can be reloaded in the meantime
+                let game = match games::get_loaded(game_info.get_name()) {
+                    Ok(game) => game,
+
+                    Err(err) => {
+                        tracing::error!("Failed to open {} addons manager: {err}", game_info.get_title());
+
+                        return;
+                    }
                 };
 
                 let settings = config::get()
-                    .games.get_game_settings(game)
+                    .games.get_game_settings(&game)
                     .unwrap();
 
                 self.enabled_addons = settings.addons
@@ -132,6 +164,25 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
 
                 self.game_info = game_info.clone();
 
+                for row in self.settings_rows.drain(..) {
+                    self.settings_group.remove(&row);
+                }
+
+                let values = settings.custom
+                    .get(game_info.get_edition())
+                    .cloned()
+                    .unwrap_or_default();
+
+                for field in settings_schema {
+                    let value = field.get_value(values.get(&field.name));
+                    let row = build_setting_row(field, value, sender.clone());
+
+                    self.settings_group.add(&row);
+                    self.settings_rows.push(row);
+                }
+
+                self.settings_group.set_visible(!self.settings_rows.is_empty());
+
                 for group in &self.addons_groups_widgets {
                     self.addons_groups_page.remove(group.widget());
                 }
@@ -194,6 +245,24 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
                 }).unwrap();
             }
 
+            GameAddonsManagerAppMsg::SetSetting { name, value } => {
+                let mut values = config::get()
+                    .games.get_game_custom_settings(self.game_info.get_name(), self.game_info.get_edition());
+
+                // Store the whole edition's map since setting names may contain dots
+                values.insert(name, value);
+
+                let property = format!("games.settings.{}.custom.{}", self.game_info.get_name(), self.game_info.get_edition());
+
+                let result = serde_json::to_value(values)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|values| config::set(property, values));
+
+                if let Err(err) = result {
+                    tracing::error!("Failed to save {} setting: {err}", self.game_info.get_name());
+                }
+            }
+
             GameAddonsManagerAppMsg::ToggleAddon { addon, enabled } => {
                 if enabled {
                     self.enabled_addons.insert(addon);
@@ -212,3 +281,117 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
         }
     }
 }
+
+/// Build preferences row for the game setting defined by the integration script
+fn build_setting_row(field: SettingsField, value: Json, sender: AsyncComponentSender<GameAddonsManagerApp>) -> adw::PreferencesRow {
+    let name = field.name;
+
+    match field.kind {
+        SettingsFieldKind::Bool { .. } => {
+            let row = adw::SwitchRow::builder()
+                .title(&field.title)
+                .active(value.as_bool().unwrap_or_default())
+                .build();
+
+            if let Some(description) = &field.description {
+                row.set_subtitle(description);
+            }
+
+            row.connect_active_notify(move |row| {
+                sender.input(GameAddonsManagerAppMsg::SetSetting {
+                    name: name.clone(),
+                    value: Json::from(row.is_active())
+                });
+            });
+
+            row.upcast()
+        }
+
+        SettingsFieldKind::Enum { values, .. } => {
+            let titles = values.iter()
+                .map(|value| value.title.as_str())
+                .collect::<Vec<_>>();
+
+            let row = adw::ComboRow::builder()
+                .title(&field.title)
+                .model(&gtk::StringList::new(&titles))
+                .build();
+
+            if let Some(description) = &field.description {
+                row.set_subtitle(description);
+            }
+
+            let selected = values.iter()
+                .position(|variant| Some(variant.name.as_str()) == value.as_str())
+                .unwrap_or_default();
+
+            row.set_selected(selected as u32);
+
+            row.connect_selected_notify(move |row| {
+                if let Some(variant) = values.get(row.selected() as usize) {
+                    sender.input(GameAddonsManagerAppMsg::SetSetting {
+                        name: name.clone(),
+                        value: Json::from(variant.name.as_str())
+                    });
+                }
+            });
+
+            row.upcast()
+        }
+
+        SettingsFieldKind::String { .. } => {
+            let row = adw::EntryRow::builder()
+                .title(&field.title)
+                .text(value.as_str().unwrap_or_default())
+                .show_apply_button(true)
+                .build();
+
+            // Entry rows don't have subtitles
+            row.set_tooltip_text(field.description.as_deref());
+
+            row.connect_apply(move |row| {
+                sender.input(GameAddonsManagerAppMsg::SetSetting {
+                    name: name.clone(),
+                    value: Json::from(row.text().as_str())
+                });
+            });
+
+            row.upcast()
+        }
+
+        SettingsFieldKind::Number { min, max, step, .. } => {
+            let step = step.unwrap_or(1.0);
+
+            let adjustment = gtk::Adjustment::new(
+                value.as_f64().unwrap_or_default(),
+                min.unwrap_or(i32::MIN as f64),
+                max.unwrap_or(i32::MAX as f64),
+                step,
+                step * 10.0,
+                0.0
+            );
+
+            // Show fractional digits only for fractional steps
+            let digits = if step.fract() == 0.0 { 0 } else { 2 };
+
+            let row = adw::SpinRow::builder()
+                .title(&field.title)
+                .adjustment(&adjustment)
+                .digits(digits)
+                .build();
+
+            if let Some(description) = &field.description {
+                row.set_subtitle(description);
+            }
+
+            row.connect_value_notify(move |row| {
+                sender.input(GameAddonsManagerAppMsg::SetSetting {
+                    name: name.clone(),
+                    value: Json::from(row.value())
+                });
+            });
+
+            row.upcast()
+        }
+    }
+}
@@ -102,12 +102,11 @@ pub fn get_download(pool: &rusty_pool::ThreadPool) -> anyhow::Result<Vec<AddonsL
 
     let mut tasks = Vec::new();
 
-    for game in games::list()?.values() {
-        let settings = config.games.get_game_settings(game)?;
+    for game in games::list()?.into_values() {
+        let settings = config.games.get_game_settings(&game)?;
 
-        // Unfortunately it's impossible to run get_game_addons and other tasks
-        // in the threads as well because lua executer cannot be run in different threads
-        // See multithread-lua branch for details
+        // Driver calls are processed by the game's own lua thread
+        // so it's safe to check different games in parallel
         tasks.push(pool.evaluate(move || -> anyhow::Result<Vec<AddonsListEntry>> {
             let mut addons = Vec::new();
 
@@ -129,7 +128,7 @@ pub fn get_download(pool: &rusty_pool::ThreadPool) -> anyhow::Result<Vec<AddonsL
                         edition: edition.name.clone()
                     };
 
-                    addons.extend(get_game_addons_downloads(&game_info, game, &edition.name, enabled_addons)?);
+                    addons.extend(get_game_addons_downloads(&game_info, &game, &edition.name, enabled_addons)?);
                 }
             }
 
@@ -1,11 +1,23 @@
 use wincompatlib::dxvk::Dxvk as WincompatlibDxvk;
 
 use crate::config;
+use crate::offline;
 use crate::components::dxvk::Dxvk;
 
 #[inline]
 pub fn get_download() -> anyhow::Result<Option<Dxvk>> {
-    let dxvk = Dxvk::from_config()?;
+    let dxvk = match Dxvk::from_config() {
+        Ok(dxvk) => dxvk,
+
+        // DXVK can't be downloaded in offline mode
+        Err(err) if offline::is_offline() => {
+            tracing::warn!("Skipping dxvk version check: {err}");
+
+            return Ok(None);
+        }
+
+        Err(err) => return Err(err)
+    };
 
     if dxvk.is_downloaded() {
         return Ok(None);
@@ -23,7 +35,17 @@ pub fn get_apply() -> anyhow::Result<Option<Dxvk>> {
         return Ok(None);
     }
 
-    let selected_dxvk = Dxvk::from_config()?;
+    let selected_dxvk = match Dxvk::from_config() {
+        Ok(dxvk) => dxvk,
+
+        Err(err) if offline::is_offline() => {
+            tracing::warn!("Skipping applied dxvk check: {err}");
+
+            return Ok(None);
+        }
+
+        Err(err) => return Err(err)
+    };
 
     let installed_dxvk = WincompatlibDxvk::get_version(prefix)?;
 
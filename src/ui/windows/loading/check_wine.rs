@@ -1,8 +1,20 @@
+use crate::offline;
 use crate::components::wine::Wine;
 
 #[inline]
 pub fn get_download() -> anyhow::Result<Option<Wine>> {
-    let wine = Wine::from_config()?;
+    let wine = match Wine::from_config() {
+        Ok(wine) => wine,
+
+        // Wine can't be downloaded in offline mode
+        Err(err) if offline::is_offline() => {
+            tracing::warn!("Skipping wine version check: {err}");
+
+            return Ok(None);
+        }
+
+        Err(err) => return Err(err)
+    };
 
     if wine.is_downloaded() {
         return Ok(None);
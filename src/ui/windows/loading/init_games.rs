@@ -50,7 +50,9 @@ fn get_game_entries(game: &Game, settings: GameSettings) -> anyhow::Result<Vec<(
 
 #[inline]
 pub fn register_games_styles() -> anyhow::Result<()> {
-    let sus = games::list()?.iter()
+    let games = games::list()?;
+
+    let sus = games.iter()
         .map(|(name, game)| game.driver.get_game_editions_list()
             .map(|editions| editions.into_iter()
                 .map(|edition| game.driver.get_details_background_style(&edition.name)
@@ -75,21 +77,9 @@ pub fn register_games_styles() -> anyhow::Result<()> {
     Ok(())
 }
 
-#[inline]
-pub fn get_games_list() -> anyhow::Result<GamesList> {
-    let settings = config::get().games;
-
-    let games = games::list()?;
-
-    let mut installed = Vec::new();
-    let mut available = Vec::with_capacity(games.len());
-    let mut outdated = Vec::new();
-
-    for game in games.values() {
-        let settings = settings.get_game_settings(game)?;
-
-        let entries = get_game_entries(game, settings)?;
-
+impl GamesList {
+    /// Sort game entries by their diff status
+    pub fn add_entries(&mut self, entries: Vec<(GameListEntry, Option<Diff>)>) {
         let installed_entries = entries.iter()
             .filter_map(|(entry, diff)| {
                 match diff {
@@ -115,14 +105,53 @@ pub fn get_games_list() -> anyhow::Result<GamesList> {
             })
             .cloned();
 
-        installed.extend(installed_entries);
-        available.extend(available_entries);
-        outdated.extend(outdated_entries);
+        self.installed.extend(installed_entries);
+        self.available.extend(available_entries);
+        self.outdated.extend(outdated_entries);
     }
+}
+
+#[inline]
+pub fn get_games_list(pool: &rusty_pool::ThreadPool) -> anyhow::Result<GamesList> {
+    let settings = config::get().games;
+
+    let games = games::list()?;
+
+    let mut list = GamesList {
+        installed: Vec::new(),
+        available: Vec::with_capacity(games.len()),
+        outdated: Vec::new()
+    };
+
+    let mut tasks = Vec::with_capacity(games.len());
+
+    // Settings are resolved in the current thread
+    // because they're written to the config file
+    for game in games.into_values() {
+        let settings = settings.get_game_settings(&game)?;
+
+        tasks.push(pool.evaluate(move || get_game_entries(&game, settings)));
+    }
+
+    for task in tasks {
+        list.add_entries(task.await_complete()?);
+    }
+
+    Ok(list)
+}
+
+/// Get games list entries of a single game
+#[inline]
+pub fn get_game_list(game: &Game) -> anyhow::Result<GamesList> {
+    let settings = config::get().games.get_game_settings(game)?;
+
+    let mut list = GamesList {
+        installed: Vec::new(),
+        available: Vec::new(),
+        outdated: Vec::new()
+    };
+
+    list.add_entries(get_game_entries(game, settings)?);
 
-    Ok(GamesList {
-        installed,
-        available,
-        outdated
-    })
+    Ok(list)
 }
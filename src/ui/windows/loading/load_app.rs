@@ -1,6 +1,8 @@
 use relm4::prelude::*;
 
 use crate::tr;
+use crate::games;
+use crate::offline;
 
 use crate::components::dxvk::Dxvk;
 use crate::components::wine::Wine;
@@ -19,7 +21,23 @@ pub struct LoadingResult {
     pub create_prefix: Option<Prefix>,
     pub download_addons: Vec<check_addons::AddonsListEntry>,
 
-    pub games_list: init_games::GamesList
+    pub games_list: init_games::GamesList,
+
+    /// Launcher works in offline mode, so displayed data may be stale
+    pub offline: bool
+}
+
+/// Show warning dialog and wait until the user closes it
+fn display_warning(sender: &AsyncComponentSender<LoadingApp>, title: String, message: String) {
+    let (closed, closed_receiver) = flume::bounded(1);
+
+    sender.input(LoadingAppMsg::DisplayWarning {
+        title,
+        message,
+        closed
+    });
+
+    let _ = closed_receiver.recv();
 }
 
 pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResult, LoadingAppMsg> {
@@ -65,10 +83,42 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
         .core_size(config.general.threads.number as usize)
         .build();
 
-    update_integrations::update_integrations(&pool).map_err(|err| LoadingAppMsg::DisplayError {
-        title: tr!("loading-updating-integrations-failed"),
-        message: err.to_string()
-    })?;
+    if offline::is_forced() {
+        offline::enable("forced by the --offline flag");
+    }
+
+    let rejected_integrations = match update_integrations::update_integrations(&pool) {
+        Ok(rejected) => rejected,
+
+        // Use already installed integrations if the network is not available
+        Err(err) if offline::is_network_error(&err) => {
+            offline::enable(&err);
+
+            Vec::new()
+        }
+
+        Err(err) => return Err(LoadingAppMsg::DisplayError {
+            title: tr!("loading-updating-integrations-failed"),
+            message: err.to_string()
+        })
+    };
+
+    if !rejected_integrations.is_empty() {
+        let message = rejected_integrations.iter()
+            .map(|integration| {
+                if integration.game.is_empty() {
+                    format!("{}: {}", integration.source, integration.reason)
+                }
+
+                else {
+                    format!("{} ({}): {}", integration.game, integration.source, integration.reason)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        display_warning(sender, tr!("loading-integrations-rejected"), message);
+    }
 
     sender.input(LoadingAppMsg::SetProgress(5.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-preparing-games")));
@@ -78,10 +128,21 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
         message: err.to_string()
     })?;
 
+    let skipped_integrations = games::skipped();
+
+    if !skipped_integrations.is_empty() {
+        let message = skipped_integrations.iter()
+            .map(|integration| format!("{}: {}", integration.game_title, integration.reason))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        display_warning(sender, tr!("loading-integrations-skipped"), message);
+    }
+
     sender.input(LoadingAppMsg::SetProgress(6.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-preparing-games-list")));
 
-    let games_list = init_games::get_games_list().map_err(|err| LoadingAppMsg::DisplayError {
+    let games_list = init_games::get_games_list(&pool).map_err(|err| LoadingAppMsg::DisplayError {
         title: tr!("loading-preparing-games-list-failed"),
         message: err.to_string()
     })?;
@@ -126,10 +187,15 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
     sender.input(LoadingAppMsg::SetProgress(12.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-games-addons")));
 
-    let download_addons = check_addons::get_download(&pool).map_err(|err| LoadingAppMsg::DisplayError {
-        title: tr!("loading-checking-games-addons-failed"),
-        message: err.to_string()
-    })?;
+    // Addons can't be downloaded in offline mode
+    let download_addons = if offline::is_offline() {
+        Vec::new()
+    } else {
+        check_addons::get_download(&pool).map_err(|err| LoadingAppMsg::DisplayError {
+            title: tr!("loading-checking-games-addons-failed"),
+            message: err.to_string()
+        })?
+    };
 
     sender.input(LoadingAppMsg::SetProgress(1.0));
 
@@ -145,6 +211,8 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
         create_prefix,
         download_addons,
 
-        games_list
+        games_list,
+
+        offline: offline::is_offline()
     })
 }
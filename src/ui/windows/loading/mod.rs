@@ -42,6 +42,14 @@ pub enum LoadingAppMsg {
     DisplayError {
         title: String,
         message: String
+    },
+
+    /// Display non-critical issue and notify the sender
+    /// when the dialog is closed
+    DisplayWarning {
+        title: String,
+        message: String,
+        closed: flume::Sender<()>
     }
 }
 
@@ -174,6 +182,27 @@ impl SimpleAsyncComponent for LoadingApp {
 
                 dialog.present();
             }
+
+            LoadingAppMsg::DisplayWarning { title, message, closed } => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&title),
+                    Some(&message)
+                );
+
+                dialog.add_response("continue", &tr!("dialog-continue"));
+                dialog.set_response_appearance("continue", adw::ResponseAppearance::Suggested);
+
+                dialog.connect_response(None, move |_, _| {
+                    let _ = closed.send(());
+                });
+
+                dialog.present();
+            }
         }
     }
 }
@@ -1,30 +1,199 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf, Component};
 
 use serde_json::Value as Json;
 
 use anime_game_core::network::minreq;
 
 use crate::config;
+use crate::offline;
 use crate::games::integrations::manifest::Manifest;
+use crate::games::integrations::signature;
+
+use crate::QUARANTINE_FOLDER;
 
 struct IntegrationInfo {
     pub source: String,
     pub manifest_body: Vec<u8>,
-    pub manifest: Manifest
+    pub manifest: Manifest,
+
+    /// Trusted keys of the source, or `None` if signatures are not required
+    pub trusted_keys: Option<Vec<String>>
+}
+
+/// Integration which was not installed because of failed signature verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedIntegration {
+    pub game: String,
+    pub source: String,
+    pub reason: String
+}
+
+/// Download detached signature of the file and verify it
+fn verify_signature(uri: &str, data: &[u8], keys: &[String]) -> anyhow::Result<()> {
+    let response = minreq::get(format!("{uri}.sig"))
+        .send()?;
+
+    // HTTP OK
+    if !(200..300).contains(&response.status_code) {
+        anyhow::bail!("Signature file {uri}.sig is not available");
+    }
+
+    signature::verify(data, response.as_bytes(), keys)
+}
+
+/// Check if the game name can be used as a folder name
+/// 
+/// Names come from remote sources, so only `[a-z0-9_-]+` are allowed
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|char| {
+        char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_' || char == '-'
+    })
+}
+
+/// Check if the path is relative and stays inside of its parent folder
+fn is_relative_path(path: &str) -> bool {
+    !path.is_empty() && Path::new(path).components().all(|component| matches!(component, Component::Normal(_)))
 }
 
+/// Get quarantine entry name for the integration with unknown game name
 #[inline]
-pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()> {
+fn get_hash_name(manifest: &[u8]) -> String {
+    format!("{:032x}", xxhash_rust::xxh3::xxh3_128(manifest))
+}
+
+/// Save rejected integration files for manual inspection
+fn quarantine(name: &str, files: &[(&str, &[u8])]) -> anyhow::Result<()> {
+    if !is_valid_name(name) {
+        anyhow::bail!("Invalid quarantine entry name: {name}");
+    }
+
+    let path = QUARANTINE_FOLDER.join(name);
+
+    std::fs::create_dir_all(&path)?;
+
+    for (file, data) in files {
+        let Some(file) = Path::new(file).file_name() else {
+            anyhow::bail!("Invalid quarantine file name: {file}");
+        };
+
+        std::fs::write(path.join(file), data)?;
+    }
+
+    Ok(())
+}
+
+/// Get path to the local integrations source
+/// 
+/// Local sources are `file://` URIs or absolute paths to the folders
+fn get_local_source(source: &str) -> Option<PathBuf> {
+    if let Some(path) = source.strip_prefix("file://") {
+        return Some(PathBuf::from(path));
+    }
+
+    let path = Path::new(source);
+
+    path.is_absolute().then(|| path.to_path_buf())
+}
+
+/// Symlink integrations of the local source to the integrations folder
+/// 
+/// Local integrations are used in place, so changes in their files
+/// are applied without updating them
+fn link_local_source(source: &Path, integrations_path: &Path) -> anyhow::Result<()> {
+    let integrations_file = source.join("integrations.json");
+
+    let folders = if integrations_file.exists() {
+        let integrations = serde_json::from_slice::<Json>(&std::fs::read(integrations_file)?)?;
+
+        let Some(integrations) = integrations.get("games").and_then(Json::as_array) else {
+            anyhow::bail!("Wrong integrations file structue");
+        };
+
+        integrations.iter()
+            .filter_map(Json::as_str)
+            .map(|game| source.join("games").join(game))
+            .collect::<Vec<_>>()
+    }
+
+    else if source.join("manifest.json").exists() {
+        vec![source.to_path_buf()]
+    }
+
+    else {
+        anyhow::bail!("Source {source:?} doesn't have integrations.json or manifest.json file");
+    };
+
+    std::fs::create_dir_all(integrations_path)?;
+
+    for folder in folders {
+        let manifest = Manifest::from_file(folder.join("manifest.json"))?;
+
+        let link_path = integrations_path.join(&manifest.game_name);
+
+        if link_path.is_symlink() {
+            if std::fs::read_link(&link_path)? == folder {
+                continue;
+            }
+
+            std::fs::remove_file(&link_path)?;
+        }
+
+        // Replace downloaded version of the integration
+        else if link_path.exists() {
+            std::fs::remove_dir_all(&link_path)?;
+        }
+
+        tracing::debug!("Linking local {} integration from {folder:?}", manifest.game_name);
+
+        std::os::unix::fs::symlink(&folder, &link_path)?;
+    }
+
+    Ok(())
+}
+
+#[inline]
+pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<Vec<RejectedIntegration>> {
     let config = config::get();
 
     let mut tasks = Vec::with_capacity(config.games.integrations.sources.len());
 
     for source in config.games.integrations.sources {
-        tasks.push(pool.evaluate(move || -> anyhow::Result<HashMap<String, IntegrationInfo>> {
+        if let Some(path) = get_local_source(&source) {
+            link_local_source(&path, &config.games.integrations.path)?;
+
+            continue;
+        }
+
+        // Remote sources are not updated in offline mode
+        if offline::is_offline() {
+            continue;
+        }
+
+        let trusted_keys = config.games.integrations.trusted_keys.get(&source)
+            .filter(|keys| !keys.is_empty())
+            .cloned();
+
+        let allow_unsigned = config.games.integrations.allow_unsigned;
+
+        tasks.push(pool.evaluate(move || -> anyhow::Result<(HashMap<String, IntegrationInfo>, Vec<RejectedIntegration>)> {
+            if trusted_keys.is_none() && !allow_unsigned {
+                return Ok((HashMap::new(), vec![RejectedIntegration {
+                    game: String::new(),
+                    source: source.clone(),
+                    reason: String::from("Source doesn't have trusted keys and unsigned integrations are not allowed")
+                }]));
+            }
+
+            if trusted_keys.is_none() {
+                tracing::warn!(%source, "Source doesn't have trusted keys, its integrations are not verified");
+            }
+
             let response = minreq::get(format!("{source}/integrations.json"))
                 .send()?;
 
             let mut games = HashMap::new();
+            let mut rejected = Vec::new();
 
             // HTTP OK
             if (200..300).contains(&response.status_code) {
@@ -36,34 +205,89 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
 
                 for game in integrations {
                     if let Some(game) = game.as_str() {
-                        let bytes = minreq::get(format!("{source}/games/{game}/manifest.json"))
+                        if !is_valid_name(game) {
+                            rejected.push(RejectedIntegration {
+                                game: game.to_string(),
+                                source: source.clone(),
+                                reason: String::from("Invalid integration name")
+                            });
+
+                            continue;
+                        }
+
+                        let manifest_uri = format!("{source}/games/{game}/manifest.json");
+
+                        let bytes = minreq::get(&manifest_uri)
                             .send()?.into_bytes();
 
+                        if let Some(keys) = &trusted_keys {
+                            if let Err(err) = verify_signature(&manifest_uri, &bytes, keys) {
+                                quarantine(game, &[("manifest.json", &bytes)])?;
+
+                                rejected.push(RejectedIntegration {
+                                    game: game.to_string(),
+                                    source: source.clone(),
+                                    reason: format!("Manifest signature verification failed: {err}")
+                                });
+
+                                continue;
+                            }
+                        }
+
                         let manifest = Manifest::from_json(&serde_json::from_slice(&bytes)?)?;
 
                         games.insert(game.to_string(), IntegrationInfo {
                             source: format!("{source}/games/{game}"),
                             manifest_body: bytes,
-                            manifest
+                            manifest,
+                            trusted_keys: trusted_keys.clone()
                         });
                     }
                 }
             }
 
             else {
-                let response = minreq::get(format!("{source}/manifest.json"))
+                let manifest_uri = format!("{source}/manifest.json");
+
+                let response = minreq::get(&manifest_uri)
                     .send()?;
 
                 // HTTP OK
                 if (200..300).contains(&response.status_code) {
                     let bytes = response.into_bytes();
 
+                    // Untrusted manifest is not parsed, so its game name is not known
+                    if let Some(keys) = &trusted_keys {
+                        if let Err(err) = verify_signature(&manifest_uri, &bytes, keys) {
+                            quarantine(&get_hash_name(&bytes), &[("manifest.json", &bytes)])?;
+
+                            rejected.push(RejectedIntegration {
+                                game: String::new(),
+                                source: source.clone(),
+                                reason: format!("Manifest signature verification failed: {err}")
+                            });
+
+                            return Ok((games, rejected));
+                        }
+                    }
+
                     let manifest = Manifest::from_json(&serde_json::from_slice(&bytes)?)?;
 
+                    if !is_valid_name(&manifest.game_name) {
+                        rejected.push(RejectedIntegration {
+                            game: manifest.game_name,
+                            source: source.clone(),
+                            reason: String::from("Invalid integration name")
+                        });
+
+                        return Ok((games, rejected));
+                    }
+
                     games.insert(manifest.game_name.to_string(), IntegrationInfo {
                         source: source.clone(),
                         manifest_body: bytes,
-                        manifest
+                        manifest,
+                        trusted_keys
                     });
                 }
 
@@ -72,26 +296,43 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
                 }
             }
 
-            Ok(games)
+            Ok((games, rejected))
         }));
     }
 
     let mut games = HashMap::new();
+    let mut rejected = Vec::new();
 
     for task in tasks {
-        for (game, value) in task.await_complete()? {
-            games.insert(game, value);
-        }
+        let (source_games, source_rejected) = task.await_complete()?;
+
+        games.extend(source_games);
+        rejected.extend(source_rejected);
     }
 
     let mut tasks = Vec::with_capacity(games.len());
 
     for (game, info) in games {
+        if !is_relative_path(&info.manifest.script_path) {
+            rejected.push(RejectedIntegration {
+                game,
+                source: info.source,
+                reason: format!("Invalid script path: {}", info.manifest.script_path)
+            });
+
+            continue;
+        }
+
         let integration_path = config.games.integrations.path.join(&game);
 
         let manifest_path = integration_path.join("manifest.json");
         let script_path = integration_path.join(&info.manifest.script_path);
 
+        // Local integrations have higher priority than remote ones
+        if integration_path.is_symlink() {
+            continue;
+        }
+
         // Spawning new threads to read a few KBs of data is more time-consuming
         // than doing it in the same thread
         if integration_path.exists() {
@@ -104,22 +345,51 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
             }
         }
 
-        else {
-            std::fs::create_dir_all(&integration_path)?;
-        }
+        tasks.push(pool.evaluate(move || -> anyhow::Result<Option<RejectedIntegration>> {
+            let script_uri = format!("{}/{}", info.source, &info.manifest.script_path);
 
-        tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
-            let script = minreq::get(format!("{}/{}", info.source, &info.manifest.script_path))
+            let script = minreq::get(&script_uri)
                 .send()?.into_bytes();
 
+            // Keep current version of the integration if the new one is not trusted
+            if let Some(keys) = &info.trusted_keys {
+                if let Err(err) = verify_signature(&script_uri, &script, keys) {
+                    quarantine(&game, &[
+                        ("manifest.json", &info.manifest_body),
+                        (info.manifest.script_path.as_str(), &script)
+                    ])?;
+
+                    return Ok(Some(RejectedIntegration {
+                        game,
+                        source: info.source,
+                        reason: format!("Script signature verification failed: {err}")
+                    }));
+                }
+            }
+
+            std::fs::create_dir_all(&integration_path)?;
+
             std::fs::write(manifest_path, info.manifest_body)?;
             std::fs::write(script_path, script)?;
 
-            Ok(())
+            Ok(None)
         }));
     }
 
-    tasks.into_iter().try_for_each(|task| task.await_complete())?;
+    for task in tasks {
+        if let Some(integration) = task.await_complete()? {
+            rejected.push(integration);
+        }
+    }
 
-    Ok(())
+    for integration in &rejected {
+        tracing::warn!(
+            game = %integration.game,
+            source = %integration.source,
+            "Integration rejected: {}",
+            integration.reason
+        );
+    }
+
+    Ok(rejected)
 }
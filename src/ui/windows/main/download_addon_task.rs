@@ -11,6 +11,7 @@ use crate::ui::components::tasks_queue::download_diff_task::{
 
 use crate::games::integrations::Game;
 use crate::games::integrations::standards::diff::DiffInfo;
+use crate::games::integrations::standards::error as script_error;
 
 use crate::games::integrations::standards::addons::{
     Addon,
@@ -28,7 +29,8 @@ fn is_installed(game: &Game, group_name: &str, addon_name: &str, addon_path: &st
             title: tr!("addon-verify-installation-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
 }
 
@@ -39,14 +41,16 @@ fn get_diff(game: &Game, group_name: &str, addon_name: &str, addon_path: &str, e
             title: tr!("addon-find-diff-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         })?
         .and_then(|diff| diff.diff)
         .ok_or_else(|| Box::new(MainAppMsg::ShowToast {
             title: tr!("addon-not-installed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: None
+            message: None,
+            trace: None
         }))
 }
 
@@ -57,7 +61,8 @@ fn get_download(game: &Game, group_name: &str, addon_name: &str, edition: &str)
             title: tr!("addon-find-download-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
         .map(|download| download.download)
 }
@@ -76,17 +81,23 @@ pub fn get_download_addon_task(game_info: &CardInfo, addon: &Addon, group: &Addo
             title: tr!("addon-find-path-failed", {
                 "game-title" = game_info.get_title().to_owned()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: None
         }))?;
 
-    let game = unsafe {
-        games::get_unsafe(game_info.get_name())
-    };
+    let game = games::get_loaded(game_info.get_name())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-integration-not-loaded", {
+                "game-title" = game_info.get_title().to_string()
+            }),
+            message: Some(err.to_string()),
+            trace: None
+        }))?;
 
     Ok(Box::new(DownloadDiffQueuedTask {
         card_info: game_info.clone(),
         diff_info: get_diff_or_download(
-            game,
+            &game,
             &group.name,
             &addon.name,
             &download_path.to_string_lossy(),
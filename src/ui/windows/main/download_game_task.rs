@@ -14,6 +14,7 @@ use crate::ui::components::tasks_queue::download_diff_task::{
 
 use crate::games::integrations::Game;
 use crate::games::integrations::standards::diff::DiffInfo;
+use crate::games::integrations::standards::error as script_error;
 
 use crate::ui::windows::loading::check_addons::{
     AddonsListEntry,
@@ -31,7 +32,8 @@ fn is_installed(game: &Game, game_path: &str, edition: &str) -> HeapResult<bool>
             title: tr!("game-verify-installation-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
 }
 
@@ -42,14 +44,16 @@ fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -
             title: tr!("game-find-diff-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         })?
         .and_then(|diff| diff.diff)
         .ok_or_else(|| Box::new(MainAppMsg::ShowToast {
             title: tr!("game-not-installed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: None
+            message: None,
+            trace: None
         }))
 }
 
@@ -60,7 +64,8 @@ fn get_download(game: &Game, edition: &str) -> HeapResult<DiffInfo> {
             title: tr!("game-find-download-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
         .map(|download| download.download)
 }
@@ -79,7 +84,8 @@ fn get_settings(game: &Game, config: &config::Config) -> HeapResult<GameSettings
             title: tr!("game-get-settings-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
 }
 
@@ -90,7 +96,8 @@ fn get_addons(game: &Game, game_info: &CardInfo, edition: &str, enabled_addons:
             title: tr!("game-get-addons-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
 }
 
@@ -101,11 +108,16 @@ pub struct DownloadGameResult {
 
 #[inline]
 pub fn get_download_game_task(game_info: &CardInfo, config: &config::Config) -> HeapResult<DownloadGameResult> {
-    let game = unsafe {
-        games::get_unsafe(game_info.get_name())
-    };
+    let game = games::get_loaded(game_info.get_name())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-integration-not-loaded", {
+                "game-title" = game_info.get_title().to_string()
+            }),
+            message: Some(err.to_string()),
+            trace: None
+        }))?;
 
-    let settings = get_settings(game, config)?;
+    let settings = get_settings(&game, config)?;
 
     // Game installation path
     let game_path = &settings.paths[game_info.get_edition()].game;
@@ -118,13 +130,13 @@ pub fn get_download_game_task(game_info: &CardInfo, config: &config::Config) ->
             card_info: game_info.clone(),
             download_path: game_path.clone(),
             diff_info: get_diff_or_download(
-                game,
+                &game,
                 &game_path.to_string_lossy(),
                 game_info.get_edition()
             )?,
             diff_origin: DiffOrigin::Game
         }),
 
-        download_addons: get_addons(game, game_info, game_info.get_edition(), enabled_addons)?
+        download_addons: get_addons(&game, game_info, game_info.get_edition(), enabled_addons)?
     })
 }
@@ -7,13 +7,11 @@ use crate::ui::components::game_card::CardInfo;
 #[tracing::instrument]
 pub fn kill_game(info: &CardInfo) -> anyhow::Result<()> {
     // Get game driver
-    let game = unsafe {
-        games::get_unsafe(info.get_name())
-    };
+    let game = games::get_loaded(info.get_name())?;
 
     // Get game settings
     let config = config::get();
-    let settings = config.games.get_game_settings(game)?;
+    let settings = config.games.get_game_settings(&game)?;
 
     // Get game paths
     let Some(paths) = settings.paths.get(info.get_edition()) else {
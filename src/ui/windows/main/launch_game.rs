@@ -1,5 +1,5 @@
 use std::process::Command;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use adw::prelude::*;
 
@@ -14,6 +14,8 @@ use crate::config::games::settings::prelude::*;
 use crate::games::integrations::standards::prelude::*;
 
 use crate::components::wine::Wine;
+use crate::components::dxvk::Dxvk;
+use crate::components::{prefix, compatibility};
 
 use crate::games::metadata::{
     LauncherMetadata,
@@ -21,6 +23,8 @@ use crate::games::metadata::{
 };
 
 use crate::games::integrations::Game;
+use crate::games::integrations::standards::error as script_error;
+use crate::games::integrations::standards::game::StatusSeverity;
 
 use crate::games::integrations::standards::diff::{
     Diff,
@@ -68,6 +72,53 @@ pub fn addon_unavailable(addon_title: impl AsRef<str>, group_title: impl AsRef<s
     Ok(receiver.recv()?)
 }
 
+/// Show incompatible components warning and wait for the user's decision
+///
+/// Returns true if the game should be launched anyway
+pub fn incompatible_components(info: &CardInfo, status: &GameStatus) -> anyhow::Result<bool> {
+    let mut message = tr!("launch-incompatible-components", {
+        "game-title" = info.get_title()
+    });
+
+    if let Some(reason) = &status.reason {
+        message = format!("{message}\n\n{reason}");
+    }
+
+    let critical = status.severity == StatusSeverity::Critical;
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    gtk::glib::MainContext::default().spawn(async move {
+        let window = unsafe {
+            MAIN_WINDOW.as_ref().unwrap_unchecked()
+        };
+
+        let dialog = adw::MessageDialog::new(
+            Some(window),
+            Some(&tr!("incompatible-components")),
+            Some(&message)
+        );
+
+        dialog.add_response("stop", &tr!("dialog-stop"));
+        dialog.add_response("continue", &tr!("dialog-continue"));
+
+        dialog.set_response_appearance("stop", adw::ResponseAppearance::Suggested);
+
+        if critical {
+            dialog.set_response_appearance("continue", adw::ResponseAppearance::Destructive);
+        }
+
+        dialog.set_default_response(Some("stop"));
+        dialog.set_close_response("stop");
+
+        dialog.connect_response(None, move |_, id| sender.send(id.to_string()).unwrap());
+
+        dialog.present();
+    });
+
+    Ok(receiver.recv()? == "continue")
+}
+
 #[inline]
 pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, enabled_addons: &[GameEditionAddon]) -> anyhow::Result<Option<(PathBuf, PathBuf)>> {
     // TODO: move files of disabled addons
@@ -203,13 +254,11 @@ pub fn prepare_launch_args(config: &config::Config) -> String {
 #[tracing::instrument]
 pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
     // Get game driver
-    let game = unsafe {
-        games::get_unsafe(info.get_name())
-    };
+    let game = games::get_loaded(info.get_name())?;
 
     // Get game settings
     let config = config::get();
-    let settings = config.games.get_game_settings(game)?;
+    let settings = config.games.get_game_settings(&game)?;
 
     // Get game paths
     let Some(paths) = settings.paths.get(info.get_edition()) else {
@@ -221,11 +270,55 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
         anyhow::bail!("Unable to find {} enabled addons", info.get_title());
     };
 
+    // Get selected components versions
+    let wine = Wine::from_config()?;
+    let dxvk = Dxvk::from_config()?;
+
+    // Ask user what to do if selected components are incompatible with the game
+    let issues = compatibility::check(&game, info.get_edition(), &wine, &dxvk)?;
+
+    if let Some(status) = issues.to_status(&wine, &dxvk) {
+        tracing::warn!(?issues, "Selected components are incompatible with the game");
+
+        if !incompatible_components(info, &status)? {
+            return Ok(());
+        }
+    }
+
     // Prepare game and addons folders
-    let Some((game_path, addons_path)) = prepare_folders(game, info, paths, enabled_addons)? else {
+    let Some((game_path, addons_path)) = prepare_folders(&game, info, paths, enabled_addons)? else {
         return Ok(())
     };
 
+    // Run pre-launch hook of the integration script
+    if game.driver.has_game_pre_launch()? {
+        let result = game.driver.run_game_pre_launch(
+            &game_path.to_string_lossy(),
+            &addons_path.to_string_lossy(),
+            info.get_edition()
+        );
+
+        // Abort launching with the script's message
+        if let Err(err) = result {
+            tracing::error!("Pre-launch hook has failed: {err}");
+
+            return Err(script_error::simplify_error(err));
+        }
+    }
+
+    // Run the game. Post-exit hook is called once the pre-launch hook
+    // has succeeded, even if the game has failed to run
+    let result = run_game(&game, info, config, &wine, &game_path, &addons_path);
+
+    // Run post-exit hook of the integration script
+    let post_exit_result = run_post_exit(&game, info, &game_path, &addons_path);
+
+    result?;
+    post_exit_result
+}
+
+/// Run the game and wait until it's closed
+fn run_game(game: &Game, info: &CardInfo, config: config::Config, wine: &Wine, game_path: &Path, addons_path: &Path) -> anyhow::Result<()> {
     // Request game launch options
     let options = game.driver.get_launch_options(
         &game_path.to_string_lossy(),
@@ -233,11 +326,27 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
         info.get_edition()
     )?;
 
-    // Get selected wine version
-    let wine = Wine::from_config()?;
+    // Re-apply prefix requirements which are missing,
+    // e.g. if the prefix was created before the game was installed.
+    // Game can still work without them, so errors don't stop the launch
+    match game.driver.get_prefix_requirements(info.get_edition()) {
+        Ok(requirements) => {
+            let missing = prefix::get_missing(&config.components.wine.prefix.path, &requirements);
+
+            if !missing.is_empty() {
+                tracing::info!(?missing, "Applying missing prefix requirements");
+
+                if let Err(err) = prefix::apply(wine, &config.components.wine.prefix.path, &missing) {
+                    tracing::warn!("Failed to apply missing prefix requirements: {err}");
+                }
+            }
+        }
+
+        Err(err) => tracing::warn!("Failed to get prefix requirements: {err}")
+    }
 
     // Prepare game launching command
-    let bash_command = prepare_bash_command(&config, &wine);
+    let bash_command = prepare_bash_command(&config, wine);
     let windows_command = prepare_windows_command(&config, info, &options);
     let launch_args = prepare_launch_args(&config);
 
@@ -272,7 +381,7 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
     let started_at = chrono::Utc::now().timestamp();
 
     // Run the game
-    command.current_dir(&game_path)
+    command.current_dir(game_path)
         .spawn()?
         .wait()?;
 
@@ -300,3 +409,22 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Run post-exit hook of the integration script if it's implemented
+fn run_post_exit(game: &Game, info: &CardInfo, game_path: &Path, addons_path: &Path) -> anyhow::Result<()> {
+    if game.driver.has_game_post_exit()? {
+        let result = game.driver.run_game_post_exit(
+            &game_path.to_string_lossy(),
+            &addons_path.to_string_lossy(),
+            info.get_edition()
+        );
+
+        if let Err(err) = result {
+            tracing::error!("Post-exit hook has failed: {err}");
+
+            return Err(script_error::simplify_error(err));
+        }
+    }
+
+    Ok(())
+}
@@ -21,7 +21,9 @@ use crate::components::dxvk::*;
 
 use crate::config::games::settings::edition_addons::GameEditionAddon;
 
+use crate::games::{LoadedIntegration, LoadResult};
 use crate::games::metadata::LauncherMetadata;
+use crate::games::integrations::standards::error as script_error;
 use crate::games::integrations::standards::addons::{
     Addon,
     AddonsGroup
@@ -35,6 +37,7 @@ use crate::ui::windows::about::{
 };
 
 use crate::ui::windows::loading::load_app::LoadingResult;
+use crate::ui::windows::loading::init_games::{self, GamesList};
 
 use crate::ui::windows::game_addons_manager::{
     GameAddonsManagerApp,
@@ -49,6 +52,12 @@ use crate::ui::components::game_card::{
 
 use crate::ui::components::factory::game_card_main::CardFactory;
 
+use crate::ui::components::factory::broken_integration_row::{
+    BrokenIntegrationFactory,
+    BrokenIntegrationFactoryInput,
+    BrokenIntegrationFactoryOutput
+};
+
 use crate::ui::components::game_details::{
     GameDetailsComponent,
     GameDetailsComponentInput,
@@ -102,7 +111,13 @@ pub struct MainApp {
     outdated_games_indexes: HashMap<CardInfo, DynamicIndex>,
     available_games_indexes: HashMap<CardInfo, DynamicIndex>,
 
-    tasks_queue: AsyncController<TasksQueueComponent>
+    broken_integrations: FactoryVecDeque<BrokenIntegrationFactory>,
+    broken_integrations_indexes: HashMap<String, DynamicIndex>,
+
+    tasks_queue: AsyncController<TasksQueueComponent>,
+
+    /// Launcher works in offline mode
+    offline: bool
 }
 
 #[derive(Debug)]
@@ -158,9 +173,32 @@ pub enum MainAppMsg {
     KillGame(CardInfo),
     FinishRunningGame(CardInfo),
 
+    RetryBrokenIntegration(String),
+
+    /// Broken integration was loaded again in background
+    FinishRetryBrokenIntegration {
+        name: String,
+
+        /// Loaded integration's game cards, or `None`
+        /// if it's not compatible with the launcher
+        result: anyhow::Result<Option<anyhow::Result<GamesList>>>
+    },
+
+    RemoveBrokenIntegration(String),
+    /// Integration was reloaded in background and should replace the registered one
+    ReloadIntegration {
+        integration: LoadedIntegration,
+
+        /// Game cards of the loaded integration
+        list: Option<anyhow::Result<GamesList>>
+    },
+
     ShowToast {
         title: String,
-        message: Option<String>
+        message: Option<String>,
+
+        /// Trace of the integration script error
+        trace: Option<String>
     }
 }
 
@@ -229,6 +267,13 @@ impl SimpleAsyncComponent for MainApp {
                             }
                         },
 
+                        adw::Banner {
+                            set_title: &tr!("main-offline-banner"),
+
+                            #[watch]
+                            set_revealed: model.offline
+                        },
+
                         #[local_ref]
                         flap -> adw::Flap {
                             set_fold_policy: adw::FlapFoldPolicy::Always,
@@ -374,6 +419,30 @@ impl SimpleAsyncComponent for MainApp {
                                         set_margin_all: if model.available_games.is_empty() { 0 } else { 16 },
 
                                         set_homogeneous: true,
+                                        set_selection_mode: gtk::SelectionMode::None
+                                    },
+
+                                    gtk::Label {
+                                        set_halign: gtk::Align::Start,
+
+                                        set_margin_start: 24,
+                                        add_css_class: "title-4",
+
+                                        #[watch]
+                                        set_visible: !model.broken_integrations.is_empty(),
+
+                                        set_label: &tr!("main-broken-integrations")
+                                    },
+
+                                    #[local_ref]
+                                    broken_integrations_list_box -> gtk::ListBox {
+                                        add_css_class: "boxed-list",
+
+                                        #[watch]
+                                        set_visible: !model.broken_integrations.is_empty(),
+
+                                        set_margin_all: 16,
+
                                         set_selection_mode: gtk::SelectionMode::None
                                     }
                                 }
@@ -439,7 +508,7 @@ impl SimpleAsyncComponent for MainApp {
                         => MainAppMsg::OpenAddonsManager(info),
 
                     GameDetailsComponentOutput::ShowToast { title, message }
-                        => MainAppMsg::ShowToast { title, message }
+                        => MainAppMsg::ShowToast { title, message, trace: None }
                 }),
 
             game_details_info: CardInfo::default(),
@@ -496,6 +565,18 @@ impl SimpleAsyncComponent for MainApp {
                     }
                 }),
 
+            broken_integrations: FactoryVecDeque::builder()
+                .launch_default()
+                .forward(sender.input_sender(), |output| match output {
+                    BrokenIntegrationFactoryOutput::Retry(name)
+                        => MainAppMsg::RetryBrokenIntegration(name),
+
+                    BrokenIntegrationFactoryOutput::Remove(name)
+                        => MainAppMsg::RemoveBrokenIntegration(name)
+                }),
+
+            broken_integrations_indexes: HashMap::new(),
+
             tasks_queue: TasksQueueComponent::builder()
                 .launch(CardInfo::default())
                 .forward(sender.input_sender(), |output| match output {
@@ -505,9 +586,11 @@ impl SimpleAsyncComponent for MainApp {
                     TasksQueueComponentOutput::HideTasksFlap
                         => MainAppMsg::HideTasksFlap,
 
-                    TasksQueueComponentOutput::ShowToast { title, message }
-                        => MainAppMsg::ShowToast { title, message }
+                    TasksQueueComponentOutput::ShowToast { title, message, trace }
+                        => MainAppMsg::ShowToast { title, message, trace }
                 }),
+
+            offline: false
         };
 
         let leaflet = &model.leaflet;
@@ -521,6 +604,7 @@ impl SimpleAsyncComponent for MainApp {
         let queued_games_flow_box = model.queued_games.widget();
         let outdated_games_flow_box = model.outdated_games.widget();
         let available_games_flow_box = model.available_games.widget();
+        let broken_integrations_list_box = model.broken_integrations.widget();
 
         let widgets = view_output!();
 
@@ -549,7 +633,8 @@ impl SimpleAsyncComponent for MainApp {
             if let Err(err) = open::that(LAUNCHER_FOLDER.as_path()) {
                 sender.input(MainAppMsg::ShowToast {
                     title: tr!("main-open-launcher-folder-failed"),
-                    message: Some(err.to_string())
+                    message: Some(err.to_string()),
+                    trace: None
                 });
 
                 tracing::error!("Failed to open launcher folder: {err}");
@@ -560,7 +645,8 @@ impl SimpleAsyncComponent for MainApp {
             if let Err(err) = open::that(CONFIG_FILE.as_path()) {
                 sender.input(MainAppMsg::ShowToast {
                     title: tr!("main-open-config-file-failed"),
-                    message: Some(err.to_string())
+                    message: Some(err.to_string()),
+                    trace: None
                 });
 
                 tracing::error!("Failed to open config file: {err}");
@@ -571,7 +657,8 @@ impl SimpleAsyncComponent for MainApp {
             if let Err(err) = open::that(DEBUG_FILE.as_path()) {
                 sender.input(MainAppMsg::ShowToast {
                     title: tr!("main-open-debug-file-failed"),
-                    message: Some(err.to_string())
+                    message: Some(err.to_string()),
+                    trace: None
                 });
 
                 tracing::error!("Failed to open debug file: {err}");
@@ -590,48 +677,40 @@ impl SimpleAsyncComponent for MainApp {
     async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
         match msg {
             MainAppMsg::InitMainApp(init) => {
-                for game in init.games_list.available {
-                    let card = CardInfo::Game {
-                        name: game.game_name.clone(),
-                        title: game.game_title.clone(),
-                        developer: game.game_developer.clone(),
-                        edition: game.edition.name.clone(),
-                        picture_uri: game.card_picture.clone()
-                    };
+                self.offline = init.offline;
 
-                    self.available_games_indexes.insert(
-                        card.to_owned(),
-                        self.available_games.guard().push_back(card.to_owned())
-                    );
-                }
+                self.add_games_list(init.games_list);
 
-                for game in init.games_list.installed {
-                    let card = CardInfo::Game {
-                        name: game.game_name.clone(),
-                        title: game.game_title.clone(),
-                        developer: game.game_developer.clone(),
-                        edition: game.edition.name.clone(),
-                        picture_uri: game.card_picture.clone()
-                    };
+                let integrations = config::get().games.integrations;
 
-                    self.installed_games_indexes.insert(
-                        card.to_owned(),
-                        self.installed_games.guard().push_back(card.to_owned())
-                    );
-                }
+                if integrations.hot_reload {
+                    let sender = sender.input_sender().clone();
 
-                for game in init.games_list.outdated {
-                    let card = CardInfo::Game {
-                        name: game.game_name.clone(),
-                        title: game.game_title.clone(),
-                        developer: game.game_developer.clone(),
-                        edition: game.edition.name.clone(),
-                        picture_uri: game.card_picture.clone()
-                    };
+                    // Integration scripts are loaded by the watcher thread,
+                    // so the main thread only replaces the registered game
+                    let result = games::watcher::watch(integrations.path, move |name| {
+                        let integration = games::load(&name);
+
+                        let list = match &integration.result {
+                            Some(Ok(LoadResult::Loaded(game))) => Some(init_games::get_game_list(game)),
+                            _ => None
+                        };
 
-                    self.outdated_games_indexes.insert(
-                        card.to_owned(),
-                        self.outdated_games.guard().push_back(card.to_owned())
+                        sender.emit(MainAppMsg::ReloadIntegration {
+                            integration,
+                            list
+                        });
+                    });
+
+                    if let Err(err) = result {
+                        tracing::error!("Failed to start integrations watcher: {err}");
+                    }
+                }
+
+                for integration in games::broken() {
+                    self.broken_integrations_indexes.insert(
+                        integration.name.clone(),
+                        self.broken_integrations.guard().push_back(integration.clone())
                     );
                 }
 
@@ -685,11 +764,23 @@ impl SimpleAsyncComponent for MainApp {
                 }
 
                 else {
-                    let game = unsafe {
-                        games::get_unsafe(info.get_name())
+                    let game = match games::get_loaded(info.get_name()) {
+                        Ok(game) => game,
+
+                        Err(err) => {
+                            sender.input(MainAppMsg::ShowToast {
+                                title: tr!("game-integration-not-loaded", {
+                                    "game-title" = info.get_title()
+                                }),
+                                message: Some(err.to_string()),
+                                trace: None
+                            });
+
+                            return;
+                        }
                     };
 
-                    let settings = config::get().games.get_game_settings(game).unwrap();
+                    let settings = config::get().games.get_game_settings(&game).unwrap();
 
                     let paths = settings
                         .paths
@@ -710,7 +801,8 @@ impl SimpleAsyncComponent for MainApp {
                                 title: tr!("game-get-status-failed", {
                                     "game-title" = info.get_title()
                                 }),
-                                message: Some(err.to_string())
+                                message: Some(err.to_string()),
+                                trace: script_error::get_trace(&err)
                             });
                         }
                     }
@@ -734,13 +826,41 @@ impl SimpleAsyncComponent for MainApp {
                 let controller = GAME_ADDONS_MANAGER_APP.as_ref()
                     .unwrap_unchecked();
 
-                let game = games::get_unsafe(game_info.get_name());
+                let game = match games::get_loaded(game_info.get_name()) {
+                    Ok(game) => game,
+
+                    Err(err) => {
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("game-integration-not-loaded", {
+                                "game-title" = game_info.get_title()
+                            }),
+                            message: Some(err.to_string()),
+                            trace: None
+                        });
+
+                        return;
+                    }
+                };
 
                 match game.driver.get_addons_list(game_info.get_edition()) {
                     Ok(addons) => {
+                        let settings = game.driver.get_settings_schema(game_info.get_edition())
+                            .unwrap_or_else(|err| {
+                                sender.input(MainAppMsg::ShowToast {
+                                    title: tr!("game-get-settings-failed", {
+                                        "game-title" = game_info.get_title()
+                                    }),
+                                    message: Some(err.to_string()),
+                                    trace: script_error::get_trace(&err)
+                                });
+
+                                vec![]
+                            });
+
                         controller.emit(GameAddonsManagerAppMsg::SetGameInfo {
                             game_info,
-                            addons
+                            addons,
+                            settings
                         });
 
                         controller.widget().present();
@@ -751,7 +871,8 @@ impl SimpleAsyncComponent for MainApp {
                             title: tr!("game-get-addons-failed", {
                                 "game-title" = game_info.get_title()
                             }),
-                            message: Some(err.to_string())
+                            message: Some(err.to_string()),
+                            trace: script_error::get_trace(&err)
                         });
                     }
                 }
@@ -962,7 +1083,8 @@ impl SimpleAsyncComponent for MainApp {
                                 title: tr!("game-launch-failed", {
                                     "game-title" = info.get_title()
                                 }),
-                                message: Some(err.to_string())
+                                message: Some(err.to_string()),
+                                trace: script_error::get_trace(&err)
                             });
                         }
 
@@ -977,7 +1099,8 @@ impl SimpleAsyncComponent for MainApp {
                         title: tr!("game-kill-failed", {
                             "game-title" = info.get_title()
                         }),
-                        message: Some(err.to_string())
+                        message: Some(err.to_string()),
+                        trace: script_error::get_trace(&err)
                     });
                 }
 
@@ -1004,7 +1127,173 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
-            MainAppMsg::ShowToast { title, message } => {
+            MainAppMsg::RetryBrokenIntegration(name) => {
+                // Integration scripts can take a lot of time to load
+                std::thread::spawn(move || {
+                    let result = games::retry_broken(&name)
+                        .map(|game| game.map(|game| init_games::get_game_list(&game)));
+
+                    sender.input(MainAppMsg::FinishRetryBrokenIntegration {
+                        name,
+                        result
+                    });
+                });
+            }
+
+            MainAppMsg::FinishRetryBrokenIntegration { name, result } => {
+                match result {
+                    Ok(list) => {
+                        if let Some(index) = self.broken_integrations_indexes.remove(&name) {
+                            self.broken_integrations.guard().remove(index.current_index());
+                        }
+
+                        // Integration is not compatible with the launcher
+                        let Some(list) = list else {
+                            return;
+                        };
+
+                        match list {
+                            Ok(list) => {
+                                self.add_games_list(list);
+
+                                self.available_games.broadcast(CardComponentInput::SetInstalled(false));
+                                self.outdated_games.broadcast(CardComponentInput::SetInstalled(false));
+
+                                // Styles are requested from all the integration scripts
+                                std::thread::spawn(|| {
+                                    if let Err(err) = init_games::register_games_styles() {
+                                        tracing::error!("Failed to register games styles: {err}");
+                                    }
+                                });
+                            }
+
+                            Err(err) => {
+                                tracing::error!("Failed to get {name} games list: {err}");
+
+                                sender.input(MainAppMsg::ShowToast {
+                                    title: tr!("main-broken-integrations-retry-failed"),
+                                    message: Some(err.to_string()),
+                                    trace: script_error::get_trace(&err)
+                                });
+                            }
+                        }
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to load {name} integration: {err}");
+
+                        if let Some(index) = self.broken_integrations_indexes.get(&name) {
+                            self.broken_integrations.send(index.current_index(), BrokenIntegrationFactoryInput::SetError(err.to_string()));
+                        }
+
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("main-broken-integrations-retry-failed"),
+                            message: Some(err.to_string()),
+                            trace: script_error::get_trace(&err)
+                        });
+                    }
+                }
+            }
+
+            MainAppMsg::RemoveBrokenIntegration(name) => {
+                match games::remove_broken(&name) {
+                    Ok(()) => {
+                        if let Some(index) = self.broken_integrations_indexes.remove(&name) {
+                            self.broken_integrations.guard().remove(index.current_index());
+                        }
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to remove {name} integration: {err}");
+
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("main-broken-integrations-remove-failed"),
+                            message: Some(err.to_string()),
+                            trace: None
+                        });
+                    }
+                }
+            }
+
+            MainAppMsg::ReloadIntegration { integration, list } => {
+                let name = integration.name.clone();
+
+                tracing::info!("Reloading {name} integration");
+
+                self.remove_game_cards(&name);
+
+                if self.game_details_info.get_name() == name {
+                    sender.input(MainAppMsg::HideDetails);
+                }
+
+                match games::replace(integration) {
+                    Ok(game) => {
+                        if let Some(index) = self.broken_integrations_indexes.remove(&name) {
+                            self.broken_integrations.guard().remove(index.current_index());
+                        }
+
+                        // Integration is removed or not compatible with the launcher
+                        if game.is_none() {
+                            return;
+                        }
+
+                        match list {
+                            Some(Ok(list)) => {
+                                self.add_games_list(list);
+
+                                self.available_games.broadcast(CardComponentInput::SetInstalled(false));
+                                self.outdated_games.broadcast(CardComponentInput::SetInstalled(false));
+
+                                // Styles are requested from all the integration scripts
+                                std::thread::spawn(|| {
+                                    if let Err(err) = init_games::register_games_styles() {
+                                        tracing::error!("Failed to register games styles: {err}");
+                                    }
+                                });
+                            }
+
+                            None => (),
+
+                            Some(Err(err)) => {
+                                tracing::error!("Failed to get {name} games list: {err}");
+
+                                sender.input(MainAppMsg::ShowToast {
+                                    title: tr!("main-reload-integration-failed", {
+                                        "integration" = name
+                                    }),
+                                    message: Some(err.to_string()),
+                                    trace: script_error::get_trace(&err)
+                                });
+                            }
+                        }
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to reload {name} integration: {err}");
+
+                        if let Some(index) = self.broken_integrations_indexes.get(&name) {
+                            self.broken_integrations.send(index.current_index(), BrokenIntegrationFactoryInput::SetError(err.to_string()));
+                        }
+
+                        else if let Some(integration) = games::broken().iter().find(|integration| integration.name == name) {
+                            self.broken_integrations_indexes.insert(
+                                name.clone(),
+                                self.broken_integrations.guard().push_back(integration.clone())
+                            );
+                        }
+
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("main-reload-integration-failed", {
+                                "integration" = name
+                            }),
+                            message: Some(err.to_string()),
+                            trace: script_error::get_trace(&err)
+                        });
+                    }
+                }
+            }
+
+            MainAppMsg::ShowToast { title, message, trace } => {
                 let window = unsafe {
                     WINDOW.as_ref().unwrap_unchecked()
                 };
@@ -1027,6 +1316,22 @@ impl SimpleAsyncComponent for MainApp {
 
                     dialog.set_response_appearance("save", adw::ResponseAppearance::Suggested);
 
+                    // Show raw trace of the integration script error
+                    if let Some(trace) = trace {
+                        let label = gtk::Label::new(Some(&trace));
+
+                        label.set_selectable(true);
+                        label.set_wrap(true);
+                        label.set_xalign(0.0);
+                        label.add_css_class("monospace");
+
+                        let expander = gtk::Expander::new(Some(&tr!("dialog-error-trace")));
+
+                        expander.set_child(Some(&label));
+
+                        dialog.set_extra_child(Some(&expander));
+                    }
+
                     dialog.connect_response(Some("save"), |_, _| {
                         if let Err(err) = open::that(DEBUG_FILE.as_path()) {
                             tracing::error!("Failed to open debug file: {err}");
@@ -1043,3 +1348,79 @@ impl SimpleAsyncComponent for MainApp {
         }
     }
 }
+
+impl MainApp {
+    /// Remove game cards of the integration from the installed,
+    /// outdated and available lists
+    /// 
+    /// Running and queued games are kept as is
+    fn remove_game_cards(&mut self, name: &str) {
+        let lists = [
+            (&mut self.installed_games, &mut self.installed_games_indexes),
+            (&mut self.outdated_games, &mut self.outdated_games_indexes),
+            (&mut self.available_games, &mut self.available_games_indexes)
+        ];
+
+        for (factory, indexes) in lists {
+            indexes.retain(|info, index| {
+                if info.get_name() == name {
+                    factory.guard().remove(index.current_index());
+
+                    false
+                }
+
+                else {
+                    true
+                }
+            });
+        }
+    }
+
+    /// Add game cards to the corresponding lists
+    fn add_games_list(&mut self, list: GamesList) {
+        for game in list.available {
+            let card = CardInfo::Game {
+                name: game.game_name.clone(),
+                title: game.game_title.clone(),
+                developer: game.game_developer.clone(),
+                edition: game.edition.name.clone(),
+                picture_uri: game.card_picture.clone()
+            };
+
+            self.available_games_indexes.insert(
+                card.to_owned(),
+                self.available_games.guard().push_back(card.to_owned())
+            );
+        }
+
+        for game in list.installed {
+            let card = CardInfo::Game {
+                name: game.game_name.clone(),
+                title: game.game_title.clone(),
+                developer: game.game_developer.clone(),
+                edition: game.edition.name.clone(),
+                picture_uri: game.card_picture.clone()
+            };
+
+            self.installed_games_indexes.insert(
+                card.to_owned(),
+                self.installed_games.guard().push_back(card.to_owned())
+            );
+        }
+
+        for game in list.outdated {
+            let card = CardInfo::Game {
+                name: game.game_name.clone(),
+                title: game.game_title.clone(),
+                developer: game.game_developer.clone(),
+                edition: game.edition.name.clone(),
+                picture_uri: game.card_picture.clone()
+            };
+
+            self.outdated_games_indexes.insert(
+                card.to_owned(),
+                self.outdated_games.guard().push_back(card.to_owned())
+            );
+        }
+    }
+}
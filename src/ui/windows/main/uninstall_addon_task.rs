@@ -9,6 +9,8 @@ use crate::games::integrations::standards::addons::{
     AddonsGroup
 };
 
+use crate::games::integrations::standards::error as script_error;
+
 use crate::ui::components::game_card::CardInfo;
 use crate::ui::components::tasks_queue::delete_files_task::DeleteFilesQueuedTask;
 
@@ -23,19 +25,26 @@ pub fn get_uninstall_addon_task(game_info: &CardInfo, addon: &Addon, group: &Add
             title: tr!("addon-find-path-failed", {
                 "game-title" = game_info.get_title().to_string()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: None
         }))?;
 
-    let game = unsafe {
-        games::get_unsafe(game_info.get_name())
-    };
+    let game = games::get_loaded(game_info.get_name())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-integration-not-loaded", {
+                "game-title" = game_info.get_title().to_string()
+            }),
+            message: Some(err.to_string()),
+            trace: None
+        }))?;
 
     let paths = game.driver.get_addon_paths(&group.name, &addon.name, &addon_path.to_string_lossy(), game_info.get_edition())
         .map_err(|err| Box::new(MainAppMsg::ShowToast {
             title: tr!("addon-get-paths-failed", {
                 "game-title" = game_info.get_title().to_string()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))?
         .into_iter()
         .map(PathBuf::from)
@@ -12,6 +12,7 @@ use crate::ui::components::tasks_queue::verify_integrity_task::VerifyIntegrityQu
 
 use crate::games::integrations::Game;
 use crate::games::integrations::standards::prelude::IntegrityInfo;
+use crate::games::integrations::standards::error as script_error;
 
 use super::MainAppMsg;
 
@@ -24,7 +25,8 @@ fn get_integrity_info(game: &Game, game_path: &str, edition: &str) -> HeapResult
             title: tr!("game-get-integrity-failed ", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
 }
 
@@ -35,7 +37,8 @@ fn get_settings(game: &Game, config: &config::Config) -> HeapResult<GameSettings
             title: tr!("game-get-settings-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: Some(err.to_string())
+            message: Some(err.to_string()),
+            trace: script_error::get_trace(&err)
         }))
 }
 
@@ -47,7 +50,8 @@ fn get_game_path<'a>(game: &'a Game, edition: &str, config: &'a config::Config)
             title: tr!("game-find-path-failed", {
                 "game-title" = game.manifest.game_title.clone()
             }),
-            message: None
+            message: None,
+            trace: None
         }))
         .map(|paths| paths.game.clone())
 
@@ -55,16 +59,21 @@ fn get_game_path<'a>(game: &'a Game, edition: &str, config: &'a config::Config)
 
 #[inline]
 pub fn get_verify_game_task(game_info: &CardInfo, config: &config::Config) -> HeapResult<Box<VerifyIntegrityQueuedTask>> {
-    let game = unsafe {
-        games::get_unsafe(game_info.get_name())
-    };
+    let game = games::get_loaded(game_info.get_name())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-integration-not-loaded", {
+                "game-title" = game_info.get_title().to_string()
+            }),
+            message: Some(err.to_string()),
+            trace: None
+        }))?;
 
-    let game_path = get_game_path(game, game_info.get_edition(), config)?;
+    let game_path = get_game_path(&game, game_info.get_edition(), config)?;
 
     Ok(Box::new(VerifyIntegrityQueuedTask {
         card_info: game_info.clone(),
         integrity_info: get_integrity_info(
-            game,
+            &game,
             &game_path.to_string_lossy(),
             game_info.get_edition()
         )?,
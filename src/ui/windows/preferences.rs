@@ -5,10 +5,12 @@ use adw::prelude::*;
 use crate::tr;
 
 use crate::i18n;
+use crate::games;
 use crate::config;
 
 use crate::components::wine::Wine;
 use crate::components::dxvk::Dxvk;
+use crate::components::compatibility;
 
 use crate::config::games::wine::prelude::*;
 use crate::config::games::enhancements::prelude::*;
@@ -19,8 +21,16 @@ pub struct PreferencesApp {
     wine_versions: Vec<Wine>,
     dxvk_versions: Vec<Dxvk>,
 
-    selected_wine: Wine,
-    selected_dxvk: Dxvk
+    selected_wine: Option<Wine>,
+    selected_dxvk: Option<Dxvk>,
+
+    /// Descriptions of the selected components issues with the installed games
+    wine_issues: Vec<String>,
+    dxvk_issues: Vec<String>,
+
+    /// Number of the last started compatibility check,
+    /// used to drop results of the outdated checks
+    compatibility_check: u64
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +38,14 @@ pub enum PreferencesAppMsg {
     SelectWineVersion(u32),
     SelectDxvkVersion(u32),
 
+    CheckCompatibility,
+
+    SetCompatibilityIssues {
+        check: u64,
+        wine: Vec<String>,
+        dxvk: Vec<String>
+    },
+
     ShowToast {
         title: String,
         message: Option<String>
@@ -413,11 +431,22 @@ impl SimpleAsyncComponent for PreferencesApp {
                         }),
 
                         set_selected: model.wine_versions.iter()
-                            .position(|version| version == &model.selected_wine)
+                            .position(|version| Some(version) == model.selected_wine.as_ref())
                             .unwrap_or(0) as u32,
 
                         connect_selected_notify[sender] => move |row| {
                             sender.input(PreferencesAppMsg::SelectWineVersion(row.selected()));
+                        },
+
+                        add_suffix = &gtk::Image {
+                            set_icon_name: Some("dialog-warning-symbolic"),
+                            add_css_class: "warning",
+
+                            #[watch]
+                            set_visible: !model.wine_issues.is_empty(),
+
+                            #[watch]
+                            set_tooltip: &format!("{}\n\n{}", tr!("components-incompatible"), model.wine_issues.join("\n"))
                         }
                     },
 
@@ -438,11 +467,22 @@ impl SimpleAsyncComponent for PreferencesApp {
                         }),
 
                         set_selected: model.dxvk_versions.iter()
-                            .position(|version| version == &model.selected_dxvk)
+                            .position(|version| Some(version) == model.selected_dxvk.as_ref())
                             .unwrap_or(0) as u32,
 
                         connect_selected_notify[sender] => move |row| {
                             sender.input(PreferencesAppMsg::SelectDxvkVersion(row.selected()));
+                        },
+
+                        add_suffix = &gtk::Image {
+                            set_icon_name: Some("dialog-warning-symbolic"),
+                            add_css_class: "warning",
+
+                            #[watch]
+                            set_visible: !model.dxvk_issues.is_empty(),
+
+                            #[watch]
+                            set_tooltip: &format!("{}\n\n{}", tr!("components-incompatible"), model.dxvk_issues.join("\n"))
                         }
                     },
 
@@ -469,19 +509,25 @@ impl SimpleAsyncComponent for PreferencesApp {
     async fn init(parent: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
         let model = Self {
             wine_versions: Wine::versions()
-                .unwrap()
+                .unwrap_or_default()
                 .into_iter()
                 .take(12)
                 .collect(),
 
             dxvk_versions: Dxvk::versions()
-                .unwrap()
+                .unwrap_or_default()
                 .into_iter()
                 .take(12)
                 .collect(),
 
-            selected_wine: Wine::from_config().unwrap(),
-            selected_dxvk: Dxvk::from_config().unwrap()
+            // Selected versions can be unavailable in offline mode
+            selected_wine: Wine::from_config().ok(),
+            selected_dxvk: Dxvk::from_config().ok(),
+
+            wine_issues: Vec::new(),
+            dxvk_issues: Vec::new(),
+
+            compatibility_check: 0
         };
 
         let widgets = view_output!();
@@ -492,6 +538,8 @@ impl SimpleAsyncComponent for PreferencesApp {
             WINDOW = Some(widgets.window.clone());
         }
 
+        sender.input(PreferencesAppMsg::CheckCompatibility);
+
         AsyncComponentParts { model, widgets }
     }
 
@@ -504,12 +552,17 @@ impl SimpleAsyncComponent for PreferencesApp {
                     self.wine_versions[index as usize - 1].name.clone()
                 };
 
+                // Latest version is the first one in the list
+                self.selected_wine = self.wine_versions.get((index as usize).saturating_sub(1)).cloned();
+
                 if let Err(err) = config::set("components.wine.version", version) {
                     sender.input(PreferencesAppMsg::ShowToast {
                         title: tr!("config-property-update-failed"),
                         message: Some(err.to_string())
                     })
                 }
+
+                sender.input(PreferencesAppMsg::CheckCompatibility);
             }
 
             PreferencesAppMsg::SelectDxvkVersion(index) => {
@@ -519,12 +572,80 @@ impl SimpleAsyncComponent for PreferencesApp {
                     self.dxvk_versions[index as usize - 1].version.clone()
                 };
 
+                // Latest version is the first one in the list
+                self.selected_dxvk = self.dxvk_versions.get((index as usize).saturating_sub(1)).cloned();
+
                 if let Err(err) = config::set("components.dxvk.version", version) {
                     sender.input(PreferencesAppMsg::ShowToast {
                         title: tr!("config-property-update-failed"),
                         message: Some(err.to_string())
                     })
                 }
+
+                sender.input(PreferencesAppMsg::CheckCompatibility);
+            }
+
+            PreferencesAppMsg::CheckCompatibility => {
+                self.compatibility_check += 1;
+
+                let check = self.compatibility_check;
+
+                let (Some(wine), Some(dxvk)) = (self.selected_wine.clone(), self.selected_dxvk.clone()) else {
+                    self.wine_issues.clear();
+                    self.dxvk_issues.clear();
+
+                    return;
+                };
+
+                // Integration scripts can take a while to respond
+                // so the check is done outside of the GTK main thread
+                std::thread::spawn(move || {
+                    let mut wine_issues = Vec::new();
+                    let mut dxvk_issues = Vec::new();
+
+                    match games::installed_editions() {
+                        Ok(editions) => {
+                            for (game, edition) in editions {
+                                let issues = match compatibility::check(&game, &edition.name, &wine, &dxvk) {
+                                    Ok(issues) => issues,
+
+                                    Err(err) => {
+                                        tracing::warn!(game = %game.manifest.game_name, edition = %edition.name, "Failed to check components compatibility: {err}");
+
+                                        continue;
+                                    }
+                                };
+
+                                let title = format!("{} ({})", game.manifest.game_title, edition.title);
+
+                                if let Some(issue) = &issues.wine {
+                                    wine_issues.push(format!("{title}: {}", compatibility::get_wine_reason(issue, &wine)));
+                                }
+
+                                if let Some(issue) = &issues.dxvk {
+                                    dxvk_issues.push(format!("{title}: {}", compatibility::get_dxvk_reason(issue, &dxvk)));
+                                }
+                            }
+                        }
+
+                        Err(err) => tracing::error!("Failed to get installed games: {err}")
+                    }
+
+                    sender.input(PreferencesAppMsg::SetCompatibilityIssues {
+                        check,
+                        wine: wine_issues,
+                        dxvk: dxvk_issues
+                    });
+                });
+            }
+
+            PreferencesAppMsg::SetCompatibilityIssues { check, wine, dxvk } => {
+                // Checks can finish in any order, so only
+                // the last selected versions are displayed
+                if check == self.compatibility_check {
+                    self.wine_issues = wine;
+                    self.dxvk_issues = dxvk;
+                }
             }
 
             PreferencesAppMsg::ShowToast { title, message } => {